@@ -0,0 +1,125 @@
+//! Generates the solution registry that is compiled into the main binary.
+//!
+//! With the `registry` feature, every `src/bin/<year>/<day>.rs` file is included as a module of the main binary, which
+//! allows `all` and `time` to invoke all solutions in-process instead of spawning one `cargo run` per day. Without it,
+//! the registry is empty, so a day that does not compile does not break the other commands.
+//!
+//! The calendar overrides of `aoc.json` are passed to the solutions as `AOC_CALENDAR`, so `solution!` can reject days
+//! that are not part of the event of their year.
+use std::{collections::HashMap, env, fs, path::Path};
+
+use tinyjson::JsonValue;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+
+    // NOTE: cargo reruns the build script on every build while a watched file does not exist, so a new `aoc.json`
+    // is picked up with the next change to `src/bin`, e.g. when a day is scaffolded.
+    let config_path = Path::new(&manifest_dir).join("aoc.json");
+    if config_path.exists() {
+        println!("cargo:rerun-if-changed=aoc.json");
+        let calendar = fs::read_to_string(&config_path)
+            .map(|contents| read_calendar(&contents))
+            .unwrap_or_default();
+        println!("cargo:rustc-env=AOC_CALENDAR={calendar}");
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    let mut days: Vec<(u16, u8, String)> = vec![];
+
+    // NOTE: solutions install their own global allocator when profiling heap allocations,
+    // so they cannot be linked into a single binary in that case.
+    if env::var_os("CARGO_FEATURE_REGISTRY").is_some()
+        && env::var_os("CARGO_FEATURE_DHAT_HEAP").is_none()
+    {
+        let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+        for year_entry in fs::read_dir(&bin_dir).into_iter().flatten().flatten() {
+            let Some(year) = year_entry
+                .file_name()
+                .to_str()
+                .filter(|name| name.len() == 4)
+                .and_then(|name| name.parse::<u16>().ok())
+            else {
+                continue;
+            };
+
+            for entry in fs::read_dir(year_entry.path())
+                .into_iter()
+                .flatten()
+                .flatten()
+            {
+                let path = entry.path();
+
+                if path.extension().is_none_or(|ext| ext != "rs") {
+                    continue;
+                }
+
+                let Some(day) = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.parse::<u8>().ok())
+                    .filter(|day| (1..=25).contains(day))
+                else {
+                    continue;
+                };
+
+                days.push((year, day, path.to_string_lossy().into_owned()));
+            }
+        }
+    }
+
+    days.sort_unstable();
+
+    let mut registry = String::new();
+
+    for (year, day, path) in &days {
+        registry.push_str(&format!(
+            "#[path = {path:?}]\n#[allow(dead_code)]\nmod y{year}_day_{day:02};\n"
+        ));
+    }
+
+    registry.push_str("\n/// Every solution in `src/bin/<year>`, sorted by year and day.\n");
+    registry
+        .push_str("pub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+
+    for (year, day, _) in &days {
+        registry.push_str(&format!("    y{year}_day_{day:02}::SOLUTION,\n"));
+    }
+
+    registry.push_str("];\n");
+
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry).unwrap();
+}
+
+/// Formats the `calendar` object of `aoc.json` as `<year>:<days>` pairs, e.g. `2026:12,2027:25`.
+/// Invalid entries are skipped here and reported by the commands that read the calendar.
+fn read_calendar(contents: &str) -> String {
+    let Ok(json) = contents.parse::<JsonValue>() else {
+        return String::new();
+    };
+    let Some(calendar) = json
+        .get::<HashMap<String, JsonValue>>()
+        .and_then(|config| config.get("calendar"))
+        .and_then(|calendar| calendar.get::<HashMap<String, JsonValue>>())
+    else {
+        return String::new();
+    };
+
+    let mut entries: Vec<String> = calendar
+        .iter()
+        .filter_map(|(year, days)| {
+            let year = year.parse::<u16>().ok().filter(|year| *year >= 2015)?;
+            let days = days
+                .get::<f64>()
+                .filter(|days| (1.0..=25.0).contains(*days) && days.fract() == 0.0)?;
+            Some(format!("{year}:{days}"))
+        })
+        .collect();
+    entries.sort();
+    entries.join(",")
+}
@@ -1,3 +1,4 @@
+use advent_of_code::diagnostics::UnwrapOrReport;
 use chumsky::prelude::*;
 use tailsome::IntoOption;
 
@@ -10,7 +11,7 @@ fn parse(input: &str) -> (Vec<u32>, Vec<u32>) {
         .then(text::int::<char, Simple<_>>(10).map(|s: String| s.parse::<u32>().unwrap()))
         .separated_by(text::newline())
         .parse(input)
-        .unwrap()
+        .unwrap_or_report(input)
         .into_iter()
         .unzip()
 }
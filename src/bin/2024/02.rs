@@ -1,3 +1,4 @@
+use advent_of_code::diagnostics::UnwrapOrReport;
 use chumsky::prelude::*;
 use itertools::Itertools;
 use tailsome::IntoOption;
@@ -10,7 +11,7 @@ fn parse(input: &str) -> Vec<Vec<u32>> {
         .at_least(1)
         .separated_by(text::newline())
         .parse(input)
-        .unwrap()
+        .unwrap_or_report(input)
 }
 
 fn valid(level: &[u32]) -> bool {
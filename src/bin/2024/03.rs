@@ -1,3 +1,4 @@
+use advent_of_code::diagnostics::UnwrapOrReport;
 use chumsky::{
     prelude::{just, take_until},
     Parser,
@@ -28,7 +29,7 @@ fn parse(input: &str) -> Vec<Operation> {
     .map(|(_, o)| o)
     .repeated()
     .parse(input)
-    .unwrap()
+    .unwrap_or_report(input)
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -1,3 +1,4 @@
+use advent_of_code::diagnostics::UnwrapOrReport;
 use chumsky::prelude::*;
 use std::{ops::Deref, str::FromStr};
 use tailsome::IntoOption;
@@ -154,13 +155,13 @@ impl Puzzle {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let puzzle = Puzzle::from_str(input).unwrap();
+    let puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle.search_xmas().into_some()
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let puzzle = Puzzle::from_str(input).unwrap();
+    let puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle.search_masx().into_some()
 }
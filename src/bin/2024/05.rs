@@ -1,3 +1,4 @@
+use advent_of_code::diagnostics::UnwrapOrReport;
 use chumsky::prelude::*;
 use std::{
     collections::{HashMap, HashSet},
@@ -23,7 +24,7 @@ impl From<Vec<(u32, u32)>> for Order {
                 .and_modify(|e| {
                     e.insert(a);
                 })
-                .or_insert_with(|| HashSet::from_iter([a].into_iter()));
+                .or_insert_with(|| HashSet::from_iter([a]));
         }
 
         order
@@ -117,7 +118,7 @@ impl Puzzle {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let puzzle = Puzzle::from_str(input).unwrap();
+    let puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     let valid = puzzle.get_valid_updates();
 
@@ -129,7 +130,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let mut puzzle = Puzzle::from_str(input).unwrap();
+    let mut puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     let invalid = puzzle.correct_updates();
 
@@ -7,7 +7,7 @@ use std::{
 use tailsome::{IntoOption, IntoResult};
 use velcro::hash_set;
 
-use advent_of_code::{Coordinate, Direction};
+use advent_of_code::{diagnostics::UnwrapOrReport, Coordinate, Direction};
 
 advent_of_code::solution!(6);
 
@@ -239,7 +239,7 @@ impl Map {
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    let mut puzzle = Puzzle::from_str(input).unwrap();
+    let mut puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle.map.perform_moves(puzzle.guard);
 
@@ -250,7 +250,7 @@ pub fn part_one(input: &str) -> Option<usize> {
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let mut puzzle = Puzzle::from_str(input).unwrap();
+    let mut puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle.map.detect_loops(puzzle.guard).into_some()
 }
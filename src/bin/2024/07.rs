@@ -3,6 +3,7 @@ use std::{iter, ops::Deref, str::FromStr};
 use tailsome::IntoOption;
 
 use advent_of_code::arena::Arena;
+use advent_of_code::diagnostics::UnwrapOrReport;
 advent_of_code::solution!(7);
 
 #[derive(Clone, Copy, Debug)]
@@ -135,7 +136,7 @@ impl FromStr for Puzzle {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let puzzle = Puzzle::from_str(input).unwrap();
+    let puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle
         .ids()
@@ -147,7 +148,7 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let puzzle = Puzzle::from_str(input).unwrap();
+    let puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle
         .ids()
@@ -4,7 +4,7 @@ use std::{
     str::FromStr,
 };
 
-use advent_of_code::{Coordinate, Map};
+use advent_of_code::{diagnostics::UnwrapOrReport, Coordinate, Map};
 use chumsky::prelude::*;
 use itertools::Itertools;
 use tailsome::IntoOption;
@@ -119,13 +119,13 @@ impl Puzzle {
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    let puzzle = Puzzle::from_str(input).unwrap();
+    let puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle.antinodes(false).len().into_some()
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let puzzle = Puzzle::from_str(input).unwrap();
+    let puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle.antinodes(true).len().into_some()
 }
@@ -1,3 +1,4 @@
+use advent_of_code::diagnostics::UnwrapOrReport;
 use chumsky::prelude::*;
 use std::{
     fmt::Debug,
@@ -185,13 +186,13 @@ impl Filesystem {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let mut filesystem = Filesystem::from(Input::from_str(input).unwrap());
+    let mut filesystem = Filesystem::from(Input::from_str(input).unwrap_or_report(input));
     filesystem.compress_v1();
     filesystem.checksum().into_some()
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let mut filesystem = Filesystem::from(Input::from_str(input).unwrap());
+    let mut filesystem = Filesystem::from(Input::from_str(input).unwrap_or_report(input));
     filesystem.compress_v2();
     filesystem.checksum().into_some()
 }
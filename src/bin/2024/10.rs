@@ -8,7 +8,7 @@ use tailsome::IntoOption;
 use text::newline;
 use velcro::hash_set;
 
-use advent_of_code::{Coordinate, Direction, Map};
+use advent_of_code::{diagnostics::UnwrapOrReport, Coordinate, Direction, Map};
 
 advent_of_code::solution!(10);
 
@@ -118,7 +118,7 @@ impl Puzzle {
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    let puzzle = Puzzle::from_str(input).unwrap();
+    let puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle
         .trailheads()
@@ -129,7 +129,7 @@ pub fn part_one(input: &str) -> Option<usize> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let puzzle = Puzzle::from_str(input).unwrap();
+    let puzzle = Puzzle::from_str(input).unwrap_or_report(input);
 
     puzzle
         .trailheads()
@@ -1,5 +1,6 @@
 use std::{str::FromStr, sync::Arc};
 
+use advent_of_code::diagnostics::UnwrapOrReport;
 use chumsky::prelude::*;
 use dashmap::DashMap;
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
@@ -92,7 +93,7 @@ impl Puzzle {
             } else {
                 let digits = stone.ilog10();
                 if digits % 2 == 1 {
-                    let cutoff = 10i32.pow((digits + 1) / 2) as u64;
+                    let cutoff = 10i32.pow(digits.div_ceil(2)) as u64;
                     let left = stone / cutoff;
                     let right = stone - left * cutoff;
                     vec![left, right]
@@ -123,12 +124,12 @@ impl Puzzle {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let mut puzzle = Puzzle::from_str(input).unwrap();
+    let mut puzzle = Puzzle::from_str(input).unwrap_or_report(input);
     puzzle.blink(25).into_some()
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let mut puzzle = Puzzle::from_str(input).unwrap();
+    let mut puzzle = Puzzle::from_str(input).unwrap_or_report(input);
     puzzle.blink(75).into_some()
 }
 
@@ -0,0 +1,217 @@
+use std::{
+    fmt::{self, Display},
+    panic,
+    sync::Once,
+};
+
+use chumsky::error::{Simple, SimpleReason};
+
+/// A readable report of the errors of a failed parse, with the offending lines of the input.
+///
+/// ```text
+/// error: found 'x', expected end of input or newline
+///  --> line 2, column 2
+///   |
+/// 2 | 3x
+///   |  ^
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseReport {
+    lines: Vec<String>,
+}
+
+impl ParseReport {
+    pub fn new(input: &str, errors: &[Simple<char>]) -> Self {
+        let mut lines = vec![];
+
+        for (index, error) in errors.iter().enumerate() {
+            if index > 0 {
+                lines.push(String::new());
+            }
+            lines.push(format!("error: {}", message(error)));
+            lines.extend(snippet(input, error.span().start, error.span().end));
+
+            if let SimpleReason::Unclosed { span, delimiter } = error.reason() {
+                lines.push(format!("note: {} opened here", token(Some(delimiter))));
+                lines.extend(snippet(input, span.start, span.end));
+            }
+        }
+
+        ParseReport { lines }
+    }
+}
+
+impl Display for ParseReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lines.join("\n"))
+    }
+}
+
+/// Unwraps the result of a chumsky parser, panicking with a [`ParseReport`] of the errors.
+/// The runner prints the report instead of the panic message.
+pub trait UnwrapOrReport<T> {
+    fn unwrap_or_report(self, input: &str) -> T;
+}
+
+impl<T> UnwrapOrReport<T> for Result<T, Vec<Simple<char>>> {
+    #[track_caller]
+    fn unwrap_or_report(self, input: &str) -> T {
+        match self {
+            Ok(value) => value,
+            Err(errors) => panic::panic_any(ParseReport::new(input, &errors)),
+        }
+    }
+}
+
+/// Silences the panic message of panics with a [`ParseReport`], other panics are printed as before.
+pub(crate) fn silence_reports() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !info.payload().is::<ParseReport>() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn message(error: &Simple<char>) -> String {
+    let mut message = match error.reason() {
+        SimpleReason::Custom(message) => message.clone(),
+        SimpleReason::Unclosed { delimiter, .. } => {
+            format!("unclosed delimiter {}", token(Some(delimiter)))
+        }
+        SimpleReason::Unexpected => {
+            let mut expected: Vec<String> = error.expected().map(|e| token(e.as_ref())).collect();
+            expected.sort();
+            expected.dedup();
+
+            let found = token(error.found());
+            match expected.split_last() {
+                None => format!("unexpected {found}"),
+                Some((last, [])) => format!("found {found}, expected {last}"),
+                Some((last, rest)) => {
+                    format!("found {found}, expected {} or {last}", rest.join(", "))
+                }
+            }
+        }
+    };
+
+    if let Some(label) = error.label() {
+        message.push_str(&format!(" while parsing {label}"));
+    }
+    message
+}
+
+/// Describes a token, `None` is the end of the input.
+fn token(token: Option<&char>) -> String {
+    match token {
+        None => "end of input".into(),
+        Some('\n') => "newline".into(),
+        Some('\r') => "carriage return".into(),
+        Some('\t') => "tab".into(),
+        Some(' ') => "space".into(),
+        Some(c) => format!("'{c}'"),
+    }
+}
+
+/// Shows the line of the input that contains the span, with a caret under the span.
+/// Spans are indices of chars, as chumsky parses strings char by char.
+fn snippet(input: &str, start: usize, end: usize) -> Vec<String> {
+    let mut line_start = 0;
+    let mut line_number = 1;
+    for (index, c) in input.chars().enumerate().take(start) {
+        if c == '\n' {
+            line_start = index + 1;
+            line_number += 1;
+        }
+    }
+
+    let line: String = input
+        .chars()
+        .skip(line_start)
+        .take_while(|c| *c != '\n')
+        .collect();
+    let line = line.trim_end_matches('\r');
+
+    let column = start - line_start;
+    let width = end
+        .saturating_sub(start)
+        .min(line.chars().count().saturating_sub(column))
+        .max(1);
+
+    let gutter = " ".repeat(line_number.to_string().len());
+    vec![
+        format!("{gutter}--> line {line_number}, column {}", column + 1),
+        format!("{gutter} |"),
+        format!("{line_number} | {line}"),
+        format!("{gutter} | {}{}", " ".repeat(column), "^".repeat(width)),
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use chumsky::prelude::*;
+
+    use super::ParseReport;
+
+    fn report(input: &str) -> String {
+        let errors = crate::digit()
+            .repeated()
+            .at_least(1)
+            .separated_by(text::newline())
+            .then_ignore(end())
+            .parse(input)
+            .unwrap_err();
+
+        ParseReport::new(input, &errors).to_string()
+    }
+
+    #[test]
+    fn reports_trailing_newline() {
+        assert_eq!(
+            report("12\n34\n"),
+            [
+                "error: unexpected end of input",
+                " --> line 3, column 1",
+                "  |",
+                "3 | ",
+                "  | ^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn reports_unexpected_token() {
+        assert_eq!(
+            report("12\n3x"),
+            [
+                "error: found 'x', expected carriage return, end of input or newline",
+                " --> line 2, column 2",
+                "  |",
+                "2 | 3x",
+                "  |  ^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn reports_custom_errors() {
+        let errors = vec![Simple::custom(3..6, "number too large")];
+        assert_eq!(
+            ParseReport::new("1, 999", &errors).to_string(),
+            [
+                "error: number too large",
+                " --> line 1, column 4",
+                "  |",
+                "1 | 1, 999",
+                "  |    ^^^",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -11,6 +11,8 @@ pub mod template;
 
 pub mod arena;
 
+pub mod diagnostics;
+
 pub fn int<T>() -> impl Parser<char, T, Error = Simple<char>>
 where
     T: FromStr,
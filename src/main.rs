@@ -1,79 +1,245 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, check_examples, dhat, download, examples, export, migrate, read, readme, scaffold, solve,
+    status, time,
+};
+use advent_of_code::template::run_multi::Mode;
+use advent_of_code::template::{DaySet, PuzzleId, DEFAULT_TEMPLATE};
 use args::{parse, AppArguments};
+use std::path::PathBuf;
+use std::time::Duration;
 
-#[cfg(feature = "today")]
-use advent_of_code::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
+mod registry {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
+/// Measures the peak heap memory of the solutions that run in-process.
+#[global_allocator]
+static ALLOC: advent_of_code::template::alloc::CountingAlloc =
+    advent_of_code::template::alloc::CountingAlloc;
+
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::{export::ExportFormat, time::ReportFormat};
+    use advent_of_code::template::{Day, DaySet, Year};
+    use std::path::PathBuf;
     use std::process;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            days: DaySet,
+            force: bool,
         },
         Read {
-            day: Day,
+            days: DaySet,
         },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            watch: bool,
+            test: bool,
         },
         All {
+            days: Option<DaySet>,
             release: bool,
+            bins: bool,
+            jobs: usize,
+            timeout: Option<u64>,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            days: Option<DaySet>,
             store: bool,
+            bins: bool,
+            compare: Option<Option<String>>,
+            check: Option<f64>,
+            report: Option<ReportFormat>,
+        },
+        CheckExamples {
+            days: Option<DaySet>,
         },
+        Examples {
+            day: Day,
+            write: bool,
+        },
+        Status {
+            json: bool,
+        },
+        Export {
+            days: Option<DaySet>,
+            format: ExportFormat,
+        },
+        Readme,
+        Dhat {
+            profile: Option<PathBuf>,
+            compare: Option<PathBuf>,
+        },
+        Migrate,
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// The percentage by which `time --check` allows parts to be slower than their stored benchmark.
+    const DEFAULT_THRESHOLD: f64 = 10.0;
+
+    /// Parses a percentage, e.g. `10%` or `10`.
+    fn parse_percent(s: &str) -> Result<f64, String> {
+        s.trim_end_matches('%')
+            .parse::<f64>()
+            .ok()
+            .filter(|percent| *percent >= 0.0)
+            .ok_or_else(|| format!("expecting a percentage, e.g. `10%`, got `{s}`"))
+    }
+
+    /// Parses a day that is part of the year's event.
+    fn parse_day(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Day, Box<dyn std::error::Error>> {
+        let day: String = args.free_from_str()?;
+        Ok(Day::parse_in(&day, year)?)
+    }
+
+    /// Parses a selection of days that are part of the year's event.
+    fn parse_days(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<DaySet, Box<dyn std::error::Error>> {
+        let days: String = args.free_from_str()?;
+        Ok(DaySet::parse_in(&days, year)?)
+    }
+
+    /// Parses an optional selection of days that are part of the year's event.
+    fn parse_opt_days(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Option<DaySet>, Box<dyn std::error::Error>> {
+        let days: Option<String> = args.opt_free_from_str()?;
+        Ok(days.map(|days| DaySet::parse_in(&days, year)).transpose()?)
+    }
+
+    /// Parses the command and the year it applies to, which defaults to `AOC_YEAR`.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
+        let year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => Year::from_env().ok_or("no year specified, set `AOC_YEAR` or pass `--year`")?,
+        };
+
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                bins: args.contains("--bins"),
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+                timeout: args.opt_value_from_str("--timeout")?,
+                days: parse_opt_days(&mut args, year)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let bins = args.contains("--bins");
+
+                let check = args.contains("--check");
+                let threshold = args.opt_value_from_fn("--threshold", parse_percent)?;
+
+                // NOTE: `--compare` compares with the previous runs, `--compare-to` with the runs at a revision.
+                let compare = match args.opt_value_from_str("--compare-to")? {
+                    Some(revision) => Some(Some(revision)),
+                    None => args.contains("--compare").then_some(None),
+                };
+
+                let report = args.opt_value_from_str("--report")?;
+
+                if check && (all || store || compare.is_some() || report.is_some()) {
+                    return Err("`--check` cannot be combined with `--all`, `--store`, `--compare`, `--compare-to` or `--report`".into());
+                }
+                if threshold.is_some() && !check {
+                    return Err("`--threshold` can only be used with `--check`".into());
+                }
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    days: parse_opt_days(&mut args, year)?,
                     store,
+                    bins,
+                    compare,
+                    check: check.then(|| threshold.unwrap_or(DEFAULT_THRESHOLD)),
+                    report,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                force: args.contains("--force"),
+                days: parse_days(&mut args, year)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                days: parse_days(&mut args, year)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                day: parse_day(&mut args, year)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            Some("solve") => {
+                let day = parse_day(&mut args, year)?;
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let watch = args.contains("--watch");
+
+                if watch && dhat {
+                    return Err("`--watch` cannot be combined with `--dhat`".into());
+                }
+                if watch && submit.is_some() {
+                    return Err("`--watch` cannot be combined with `--submit`".into());
+                }
+
+                AppArguments::Solve {
+                    day,
+                    release,
+                    submit,
+                    dhat,
+                    watch,
+                    test: args.contains("--test"),
+                }
+            }
+            Some("check-examples") => AppArguments::CheckExamples {
+                days: parse_opt_days(&mut args, year)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                day: parse_day(&mut args, year)?,
+                write: args.contains("--write"),
+            },
+            Some("status") => AppArguments::Status {
+                json: args.contains("--json"),
+            },
+            Some("export") => AppArguments::Export {
+                days: args
+                    .opt_value_from_str::<_, String>("--days")?
+                    .map(|days| DaySet::parse_in(&days, year))
+                    .transpose()?,
+                format: args
+                    .opt_value_from_str("--format")?
+                    .unwrap_or(ExportFormat::Csv),
+            },
+            Some("readme") => AppArguments::Readme,
+            Some("dhat") => AppArguments::Dhat {
+                compare: args.opt_value_from_os_str("--compare", |s| {
+                    Ok::<PathBuf, String>(PathBuf::from(s))
+                })?,
+                profile: args.opt_free_from_os_str(|s| Ok::<PathBuf, String>(PathBuf::from(s)))?,
             },
+            Some("migrate") => AppArguments::Migrate,
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -91,7 +257,17 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+}
+
+/// Solutions run in-process through the registry, unless `--bins` requests one binary per day or the
+/// `registry` feature is disabled.
+fn get_mode(bins: bool, is_release: bool) -> Mode {
+    if bins || !cfg!(feature = "registry") {
+        Mode::Binaries { is_release }
+    } else {
+        Mode::Registry(registry::SOLUTIONS)
     }
 }
 
@@ -101,39 +277,107 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                days,
+                release,
+                bins,
+                jobs,
+                timeout,
+            } => all::handle(
+                year,
+                &days.unwrap_or_else(DaySet::all),
+                get_mode(bins, release),
+                jobs,
+                timeout.map(Duration::from_secs),
+            ),
+            AppArguments::Time {
+                days,
+                all,
+                store,
+                bins,
+                compare,
+                check,
+                report,
+            } => match check {
+                Some(threshold) => time::check(year, days, get_mode(bins, true), threshold),
+                None => time::handle(
+                    year,
+                    days,
+                    get_mode(bins, true),
+                    all,
+                    store,
+                    compare,
+                    report,
+                ),
+            },
+            AppArguments::Download { days, force } => download::handle_days(year, &days, force),
+            AppArguments::Read { days } => days
+                .days(year)
+                .into_iter()
+                .for_each(|day| read::handle(PuzzleId::new(year, day))),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                let puzzle = PuzzleId::new(year, day);
+                // NOTE: download first, so the template can use the title of the puzzle.
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle, false);
                 }
+                scaffold::handle(
+                    puzzle,
+                    overwrite,
+                    template.as_deref().unwrap_or(DEFAULT_TEMPLATE),
+                );
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                watch,
+                test,
+            } => {
+                let puzzle = PuzzleId::new(year, day);
+                if watch {
+                    solve::watch(puzzle, release, test);
+                } else {
+                    solve::handle(puzzle, release, dhat, submit);
+                }
+            }
+            AppArguments::CheckExamples { days } => {
+                if !cfg!(feature = "registry") {
+                    eprintln!("`check-examples` runs solutions in-process and requires the `registry` feature.");
+                    std::process::exit(1);
+                }
+                check_examples::handle(year, &days.unwrap_or_else(DaySet::all), registry::SOLUTIONS)
+            }
+            AppArguments::Examples { day, write } => {
+                examples::handle(PuzzleId::new(year, day), write)
+            }
+            AppArguments::Status { json } => status::handle(year, json),
+            AppArguments::Export { days, format } => export::handle(year, days, format),
+            AppArguments::Readme => readme::handle(year),
+            AppArguments::Dhat { profile, compare } => dhat::handle(
+                &profile.unwrap_or_else(|| PathBuf::from(dhat::DEFAULT_PROFILE)),
+                compare.as_deref(),
+            ),
+            AppArguments::Migrate => migrate::handle(year),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match PuzzleId::today() {
+                    Some(puzzle) => {
+                        download::handle(puzzle, false);
+                        scaffold::handle(puzzle, false, DEFAULT_TEMPLATE);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run during the days of this year's \
+                            event in december. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,98 @@
+//! Module that measures the peak heap memory of a solution part, see [`measure`].
+//! The [`CountingAlloc`] is installed as the global allocator of the main binary and of the solution bins.
+//! Outside of a measurement, every allocation only loads a flag.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicBool, AtomicIsize, Ordering},
+};
+
+static IS_MEASURING: AtomicBool = AtomicBool::new(false);
+/// Bytes allocated since the start of the measurement, negative if memory from before was freed.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// An allocator that forwards to the system allocator and counts the bytes allocated during a measurement.
+pub struct CountingAlloc;
+
+impl CountingAlloc {
+    #[inline]
+    fn count(size: usize, is_alloc: bool) {
+        if !IS_MEASURING.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let size = isize::try_from(size).unwrap_or(isize::MAX);
+        if is_alloc {
+            let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        } else {
+            CURRENT.fetch_sub(size, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size(), true);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size(), true);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        Self::count(layout.size(), false);
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size >= layout.size() {
+            Self::count(new_size - layout.size(), true);
+        } else {
+            Self::count(layout.size() - new_size, false);
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Runs `f` and returns the peak number of bytes it held on the heap, including allocations of other threads.
+/// Returns `None` if the [`CountingAlloc`] is not the global allocator, e.g. while profiling with dhat,
+/// or if another measurement is running.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<u64>) {
+    if IS_MEASURING
+        .compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed)
+        .is_err()
+    {
+        return (f(), None);
+    }
+
+    let measuring = Measuring;
+
+    // NOTE: a probe allocation is only counted if the allocator is installed.
+    CURRENT.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+    drop(black_box(Box::new(0_u64)));
+    let is_installed = PEAK.load(Ordering::Relaxed) > 0;
+
+    CURRENT.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+
+    let result = f();
+
+    drop(measuring);
+    let peak = u64::try_from(PEAK.load(Ordering::Relaxed)).unwrap_or_default();
+
+    (result, is_installed.then_some(peak))
+}
+
+/// Ends the measurement when dropped, also when the part panics.
+struct Measuring;
+
+impl Drop for Measuring {
+    fn drop(&mut self) {
+        IS_MEASURING.store(false, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,528 @@
+/// Module that talks to the Advent of Code website to download inputs and puzzles and to submit answers.
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    str::FromStr,
+    sync::Once,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{html, PuzzleId, CONFIG_PATH};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Identifies the tool, the contact of the repository's owner is appended when configured in `aoc.json`.
+const USER_AGENT: &str = concat!("advent_of_code/", env!("CARGO_PKG_VERSION"));
+const SESSION_FILE: &str = ".adventofcode.session";
+const LAST_REQUEST_FILE: &str = "data/.last-request";
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// An error that occurs while talking to the Advent of Code website.
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session cookie was configured.
+    MissingSession,
+    /// The session file exists, but could not be read.
+    SessionFile(PathBuf, io::Error),
+    /// The server responded with an error status.
+    Status { url: String, status: u16 },
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+}
+
+impl Error for AocClientError {}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or paste it into \"~/{SESSION_FILE}\"."
+            ),
+            AocClientError::SessionFile(path, e) => {
+                write!(f, "failed to read session file \"{}\": {e}", path.display())
+            }
+            AocClientError::Status { url, status: 400 } => write!(
+                f,
+                "request to {url} was rejected (status 400). The session cookie may have expired."
+            ),
+            AocClientError::Status { url, status: 404 } => write!(
+                f,
+                "{url} was not found (status 404). The puzzle may not be unlocked yet."
+            ),
+            AocClientError::Status { url, status } => {
+                write!(f, "request to {url} failed with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+        }
+    }
+}
+
+/// The verdict of the puzzle server on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    /// Answers were submitted too recently.
+    TooSoon,
+    /// The part has been solved already or is not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::Incorrect => f.write_str("incorrect"),
+            Verdict::TooSoon => f.write_str("too_soon"),
+            Verdict::WrongLevel => f.write_str("wrong_level"),
+            Verdict::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "too_soon" => Ok(Verdict::TooSoon),
+            "wrong_level" => Ok(Verdict::WrongLevel),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// The response of the puzzle server to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    /// The message of the server, converted to markdown.
+    pub message: String,
+}
+
+impl Submission {
+    fn parse(html: &str) -> Self {
+        let message = html::to_markdown(html).trim().to_string();
+
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        };
+
+        Self { verdict, message }
+    }
+}
+
+/// Limits the rate of requests to the puzzle server across runs, by persisting the time of the last request.
+pub struct Throttle {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(path: impl Into<PathBuf>, interval: Duration) -> Self {
+        Self {
+            path: path.into(),
+            interval,
+        }
+    }
+
+    /// Waits until the interval since the last request has passed and records the time of the
+    /// current request. Returns how long the request was delayed.
+    pub fn wait(&self) -> Duration {
+        let delay = self
+            .last_request()
+            .and_then(|last| {
+                (last + self.interval)
+                    .duration_since(SystemTime::now())
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        if !delay.is_zero() {
+            println!(
+                "⏳ Waiting {:.1}s before the next request to the puzzle server...",
+                delay.as_secs_f64()
+            );
+            thread::sleep(delay);
+        }
+
+        // NOTE: failing to persist the timestamp should not fail the request.
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) {
+            let _ = fs::write(&self.path, now.as_millis().to_string());
+        }
+
+        delay
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let millis: u64 = fs::read_to_string(&self.path).ok()?.trim().parse().ok()?;
+        UNIX_EPOCH.checked_add(Duration::from_millis(millis))
+    }
+}
+
+/// A client for the Advent of Code website, authenticated with a session cookie.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    user_agent: String,
+    agent: ureq::Agent,
+    throttle: Option<Throttle>,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            user_agent: USER_AGENT.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+            throttle: None,
+        }
+    }
+
+    /// Adds the contact of the repository's owner to the user agent, e.g. a repository url or an email address.
+    #[must_use]
+    pub fn with_contact(mut self, contact: &str) -> Self {
+        self.user_agent = format!("{USER_AGENT} ({contact})");
+        self
+    }
+
+    /// Throttles all requests of the client.
+    #[must_use]
+    pub fn with_throttle(mut self, throttle: Throttle) -> Self {
+        self.throttle = Some(throttle);
+        self
+    }
+
+    /// Creates a client from the environment:
+    ///
+    ///  - the session cookie is read from `AOC_SESSION`, or from the file at `AOC_SESSION_FILE`,
+    ///    which defaults to `~/.adventofcode.session`.
+    ///  - the website is read from `AOC_BASE_URL`, which defaults to `https://adventofcode.com`.
+    ///  - the contact for the user agent is read from the `contact` key of `aoc.json`.
+    ///
+    /// Requests are throttled to one every 5 seconds, the time of the last request is kept in `data/.last-request`.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let throttle = Throttle::new(LAST_REQUEST_FILE, MIN_REQUEST_INTERVAL);
+        let client = Self::new(&base_url, &read_session()?).with_throttle(throttle);
+
+        Ok(match read_contact() {
+            Some(contact) => client.with_contact(&contact),
+            None => client,
+        })
+    }
+
+    /// Downloads the puzzle input.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.puzzle_url(puzzle)))
+    }
+
+    /// Downloads the puzzle description of all unlocked parts, converted to markdown.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let html = self.get(&self.puzzle_url(puzzle))?;
+        Ok(html::to_markdown(&html))
+    }
+
+    /// Submits the answer to a part of the puzzle.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, AocClientError> {
+        let url = format!("{}/answer", self.puzzle_url(puzzle));
+        self.throttle();
+        let response = self
+            .agent
+            .post(&url)
+            .set("User-Agent", &self.user_agent)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        Ok(Submission::parse(&read_response(&url, response)?))
+    }
+
+    fn puzzle_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        self.throttle();
+        let response = self
+            .agent
+            .get(url)
+            .set("User-Agent", &self.user_agent)
+            .set("Cookie", &self.cookie())
+            .call();
+        read_response(url, response)
+    }
+
+    fn throttle(&self) {
+        if let Some(throttle) = &self.throttle {
+            throttle.wait();
+        }
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string())),
+        Err(ureq::Error::Status(status, _)) => Err(AocClientError::Status {
+            url: url.to_string(),
+            status,
+        }),
+        Err(e) => Err(AocClientError::Transport(e.to_string())),
+    }
+}
+
+/// Reads the contact of the repository's owner from the project configuration. A missing contact is reported once,
+/// as the automation guidelines of the website ask tools to identify their user.
+fn read_contact() -> Option<String> {
+    static WARNING: Once = Once::new();
+
+    let contact = match fs::read_to_string(CONFIG_PATH) {
+        Ok(contents) => parse_contact(&contents).unwrap_or_else(|e| {
+            eprintln!("Ignoring contact in {CONFIG_PATH}: {e}");
+            None
+        }),
+        Err(_) => None,
+    };
+
+    if contact.is_none() {
+        WARNING.call_once(|| {
+            eprintln!(
+                "Note: add a `contact` (e.g. your repository url or email) to {CONFIG_PATH} to identify yourself to the puzzle server."
+            );
+        });
+    }
+
+    contact
+}
+
+/// Parses the `contact` key of the project configuration, e.g. `{ "contact": "github.com/me/advent-of-code" }`.
+fn parse_contact(contents: &str) -> Result<Option<String>, String> {
+    let json = JsonValue::from_str(contents).or(Err("not valid JSON file."))?;
+
+    match json
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected JSON document to be an object.")?
+        .get("contact")
+    {
+        None => Ok(None),
+        Some(contact) => contact
+            .get::<String>()
+            .map(|contact| contact.trim())
+            .filter(|contact| !contact.is_empty())
+            .map(|contact| Some(contact.to_string()))
+            .ok_or_else(|| "expected `contact` to be a non-empty string.".into()),
+    }
+}
+
+fn read_session() -> Result<String, AocClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session);
+        }
+    }
+
+    let path = match env::var_os("AOC_SESSION_FILE") {
+        Some(path) => PathBuf::from(path),
+        None => env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(|home| PathBuf::from(home).join(SESSION_FILE))
+            .ok_or(AocClientError::MissingSession)?,
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session),
+        Ok(_) => Err(AocClientError::MissingSession),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(AocClientError::MissingSession),
+        Err(e) => Err(AocClientError::SessionFile(path, e)),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+        time::Duration,
+    };
+
+    use super::{parse_contact, AocClient, AocClientError, Throttle, Verdict, USER_AGENT};
+    use crate::{
+        day,
+        template::{PuzzleId, Year},
+    };
+
+    /// Serves a single request with the given status and body, and sends the request back to the test.
+    fn mock_server(status: u16, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            sender.send(request).unwrap();
+        });
+
+        (url, receiver)
+    }
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(Year::new(2024).unwrap(), day!(1))
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (url, request) = mock_server(200, "3   4\n");
+        let client = AocClient::new(&url, "secret\n");
+
+        assert_eq!(client.input(puzzle()).unwrap(), "3   4\n");
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=secret\r\n"));
+        assert!(request.contains(&format!("User-Agent: {USER_AGENT}\r\n")));
+    }
+
+    #[test]
+    fn sends_contact_in_user_agent() {
+        let (url, request) = mock_server(200, "3   4\n");
+        let client = AocClient::new(&url, "secret").with_contact("me@example.com");
+        client.input(puzzle()).unwrap();
+
+        let request = request.recv().unwrap();
+        assert!(request.contains(&format!("User-Agent: {USER_AGENT} (me@example.com)\r\n")));
+    }
+
+    #[test]
+    fn parses_contact() {
+        assert_eq!(
+            parse_contact(r#"{ "contact": "github.com/me/aoc" }"#),
+            Ok(Some("github.com/me/aoc".into()))
+        );
+        assert_eq!(parse_contact(r#"{ "calendar": {} }"#), Ok(None));
+        assert!(parse_contact(r#"{ "contact": "" }"#).is_err());
+        assert!(parse_contact(r#"{ "contact": 1 }"#).is_err());
+    }
+
+    #[test]
+    fn downloads_puzzle() {
+        let (url, _) = mock_server(200, "<article><h2>--- Day 1 ---</h2></article>");
+        let client = AocClient::new(&url, "secret");
+
+        assert_eq!(client.puzzle(puzzle()).unwrap(), "## --- Day 1 ---\n");
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (url, request) = mock_server(
+            200,
+            "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>",
+        );
+        let client = AocClient::new(&url, "secret");
+
+        let submission = client.submit(puzzle(), 2, "42").unwrap();
+        assert_eq!(submission.verdict, Verdict::Incorrect);
+        assert_eq!(
+            submission.message,
+            "That's not the right answer; your answer is too low."
+        );
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn returns_status_errors() {
+        let (url, _) = mock_server(404, "Not Found");
+        let client = AocClient::new(&url, "secret");
+
+        assert!(matches!(
+            client.input(puzzle()),
+            Err(AocClientError::Status { status: 404, .. })
+        ));
+    }
+
+    #[test]
+    fn throttles_requests() {
+        let path = std::env::temp_dir().join(format!("aoc-throttle-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let throttle = Throttle::new(&path, Duration::from_millis(200));
+
+        assert!(throttle.wait().is_zero());
+        assert!(!throttle.wait().is_zero());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
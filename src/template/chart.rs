@@ -0,0 +1,311 @@
+//! Module that renders benchmark timings as SVG charts, without external tools.
+use std::fmt::Write;
+
+use crate::template::timings::{format_nanos, PartTiming, Timings};
+
+const WIDTH: f64 = 720.0;
+const LABEL_WIDTH: f64 = 70.0;
+const VALUE_WIDTH: f64 = 70.0;
+const TITLE_HEIGHT: f64 = 40.0;
+const AXIS_HEIGHT: f64 = 30.0;
+const BAR_HEIGHT: f64 = 8.0;
+const ROW_HEIGHT: f64 = 24.0;
+
+const BACKGROUND: &str = "#0f0f23";
+const TEXT: &str = "#cccccc";
+const GRID: &str = "#333340";
+/// Part one in gold and part two in silver, like the stars on the puzzle calendar.
+const PART_COLORS: [&str; 2] = ["#ffff66", "#9999cc"];
+
+/// Renders the runtime of every part as a horizontal bar chart with a logarithmic time axis.
+/// Returns `None` if no part has been benched.
+pub fn runtime_svg(title: &str, timings: &Timings) -> Option<String> {
+    let rows = rows(timings);
+    let scale = LogScale::new(
+        rows.iter()
+            .flat_map(|(_, parts)| parts.iter().flatten().map(|p| &p.nanos)),
+    )?;
+
+    Some(render(
+        title,
+        &rows,
+        &scale,
+        |svg, label, part, timing, y| {
+            let bar_width = scale.x(timing.nanos) - LABEL_WIDTH;
+            let formatted = format_nanos(timing.nanos);
+            let _ = write!(
+                svg,
+                r#"<rect x="{LABEL_WIDTH}" y="{y:.1}" width="{:.1}" height="{BAR_HEIGHT}" fill="{}"><title>{label} part {}: {formatted}</title></rect><text x="{:.1}" y="{:.1}" fill="{TEXT}" font-size="9">{formatted}</text>"#,
+                bar_width.max(1.0),
+                PART_COLORS[part],
+                part + 1,
+                LABEL_WIDTH + bar_width.max(1.0) + 4.0,
+                y + BAR_HEIGHT
+            );
+        },
+    ))
+}
+
+/// Renders the distribution of the samples of every part as box plots with a logarithmic time axis:
+/// whiskers span the fastest and slowest sample, the box spans the middle half and the dot marks the mean.
+/// Parts without samples, e.g. when benched as separate binaries, only show the mean.
+/// Returns `None` if no part has been benched.
+pub fn distribution_svg(title: &str, timings: &Timings) -> Option<String> {
+    let rows = rows(timings);
+    let scale = LogScale::new(rows.iter().flat_map(|(_, parts)| {
+        parts
+            .iter()
+            .flatten()
+            .flat_map(|p| std::iter::once(&p.nanos).chain(p.quantiles.iter().flatten()))
+    }))?;
+
+    Some(render(
+        title,
+        &rows,
+        &scale,
+        |svg, label, part, timing, y| {
+            let color = PART_COLORS[part];
+            let middle = y + BAR_HEIGHT / 2.0;
+
+            if let Some([min, lower, median, upper, max]) = timing.quantiles {
+                let _ = write!(
+                    svg,
+                    r#"<g><title>{label} part {}: min {}, median {}, max {}</title><line x1="{:.1}" y1="{middle:.1}" x2="{:.1}" y2="{middle:.1}" stroke="{color}"/><rect x="{:.1}" y="{y:.1}" width="{:.1}" height="{BAR_HEIGHT}" fill="none" stroke="{color}"/><line x1="{:.1}" y1="{y:.1}" x2="{:.1}" y2="{:.1}" stroke="{color}" stroke-width="2"/></g>"#,
+                    part + 1,
+                    format_nanos(min),
+                    format_nanos(median),
+                    format_nanos(max),
+                    scale.x(min),
+                    scale.x(max),
+                    scale.x(lower),
+                    (scale.x(upper) - scale.x(lower)).max(1.0),
+                    scale.x(median),
+                    scale.x(median),
+                    y + BAR_HEIGHT
+                );
+            }
+
+            let _ = write!(
+                svg,
+                r#"<circle cx="{:.1}" cy="{middle:.1}" r="3" fill="{color}"><title>{label} part {}: mean {}</title></circle>"#,
+                scale.x(timing.nanos),
+                part + 1,
+                format_nanos(timing.nanos)
+            );
+        },
+    ))
+}
+
+/// Renders a series of timings as a small line chart with a logarithmic time axis, oldest first.
+/// Returns `None` if there are less than two timings.
+pub fn sparkline_svg(values: &[f64]) -> Option<String> {
+    const SPARK_WIDTH: f64 = 120.0;
+    const SPARK_HEIGHT: f64 = 24.0;
+
+    if values.len() < 2 {
+        return None;
+    }
+
+    let scale = LogScale::new(values.iter())?;
+    #[allow(clippy::cast_precision_loss)]
+    let step = SPARK_WIDTH / (values.len() - 1) as f64;
+
+    let points: Vec<String> = values
+        .iter()
+        .enumerate()
+        .map(|(index, nanos)| {
+            #[allow(clippy::cast_precision_loss)]
+            let x = step * index as f64;
+            let y = SPARK_HEIGHT - 2.0 - scale.position(*nanos) * (SPARK_HEIGHT - 4.0);
+            format!("{x:.1},{y:.1}")
+        })
+        .collect();
+
+    Some(format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{SPARK_WIDTH}" height="{SPARK_HEIGHT}" viewBox="0 0 {SPARK_WIDTH} {SPARK_HEIGHT}"><polyline points="{}" fill="none" stroke="{}" stroke-width="1.5"/></svg>"#,
+        points.join(" "),
+        PART_COLORS[0]
+    ))
+}
+
+type Row = (String, [Option<PartTiming>; 2]);
+
+/// Returns a row with the benched parts of every day that has been benched.
+fn rows(timings: &Timings) -> Vec<Row> {
+    timings
+        .data
+        .iter()
+        .map(|t| (format!("Day {}", t.day.into_inner()), [t.part_1, t.part_2]))
+        .filter(|(_, parts)| parts.iter().any(Option::is_some))
+        .collect()
+}
+
+/// Renders a chart with two lanes per row, one per part, and a gridline per power of ten.
+/// `draw` renders a single part at the top `y` of its lane.
+fn render(
+    title: &str,
+    rows: &[Row],
+    scale: &LogScale,
+    draw: impl Fn(&mut String, &str, usize, &PartTiming, f64),
+) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let height = TITLE_HEIGHT + ROW_HEIGHT * rows.len() as f64 + AXIS_HEIGHT;
+
+    let mut svg = String::new();
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}" font-family="monospace" font-size="12">"#
+    );
+    let _ = write!(
+        svg,
+        r#"<rect width="100%" height="100%" fill="{BACKGROUND}"/><text x="{LABEL_WIDTH}" y="24" fill="{TEXT}" font-size="14">{}</text>"#,
+        escape(title)
+    );
+
+    let plot_bottom = height - AXIS_HEIGHT;
+    for exponent in scale.min_exponent..=scale.max_exponent {
+        let x = scale.x(10_f64.powi(exponent));
+        let _ = write!(
+            svg,
+            r#"<line x1="{x:.1}" y1="{TITLE_HEIGHT}" x2="{x:.1}" y2="{plot_bottom}" stroke="{GRID}"/><text x="{x:.1}" y="{:.1}" fill="{TEXT}" text-anchor="middle">{}</text>"#,
+            plot_bottom + 18.0,
+            decade_label(exponent)
+        );
+    }
+
+    for (row, (label, parts)) in rows.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = TITLE_HEIGHT + ROW_HEIGHT * row as f64 + 3.0;
+        let _ = write!(
+            svg,
+            r#"<text x="{:.1}" y="{:.1}" fill="{TEXT}" text-anchor="end">{label}</text>"#,
+            LABEL_WIDTH - 8.0,
+            y + BAR_HEIGHT + 4.0
+        );
+
+        for (part, timing) in parts.iter().enumerate() {
+            if let Some(timing) = timing {
+                #[allow(clippy::cast_precision_loss)]
+                draw(
+                    &mut svg,
+                    label,
+                    part,
+                    timing,
+                    y + (BAR_HEIGHT + 2.0) * part as f64,
+                );
+            }
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Maps nanoseconds to `0.0..=1.0` on a logarithmic scale spanning whole powers of ten.
+struct LogScale {
+    min_exponent: i32,
+    max_exponent: i32,
+}
+
+impl LogScale {
+    fn new<'a>(values: impl Iterator<Item = &'a f64>) -> Option<Self> {
+        let (min, max) = values.filter(|v| **v > 0.0).fold(
+            None,
+            |range: Option<(f64, f64)>, v| match range {
+                Some((min, max)) => Some((min.min(*v), max.max(*v))),
+                None => Some((*v, *v)),
+            },
+        )?;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let min_exponent = min.log10().floor() as i32;
+        #[allow(clippy::cast_possible_truncation)]
+        let max_exponent = (max.log10().ceil() as i32).max(min_exponent + 1);
+
+        Some(Self {
+            min_exponent,
+            max_exponent,
+        })
+    }
+
+    /// Returns the horizontal position of a time in a chart.
+    fn x(&self, nanos: f64) -> f64 {
+        LABEL_WIDTH + self.position(nanos) * (WIDTH - LABEL_WIDTH - VALUE_WIDTH)
+    }
+
+    fn position(&self, nanos: f64) -> f64 {
+        let min = f64::from(self.min_exponent);
+        let max = f64::from(self.max_exponent);
+        ((nanos.max(f64::MIN_POSITIVE).log10() - min) / (max - min)).clamp(0.0, 1.0)
+    }
+}
+
+/// Labels a power of ten of nanoseconds, e.g. `100µs` for `5`.
+fn decade_label(exponent: i32) -> String {
+    let units = ["ns", "µs", "ms", "s"];
+    let unit = usize::try_from(exponent.div_euclid(3))
+        .unwrap_or(0)
+        .min(units.len() - 1);
+    let factor = exponent - 3 * i32::try_from(unit).unwrap_or(0);
+    format!(
+        "{}{}",
+        10_u64.pow(factor.max(0).unsigned_abs()),
+        units[unit]
+    )
+}
+
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decade_label, runtime_svg, LogScale};
+    use crate::{
+        day,
+        template::timings::{PartTiming, Timing, Timings},
+    };
+
+    #[test]
+    fn scales_logarithmically() {
+        let scale = LogScale::new([50.0, 3.2e9].iter()).unwrap();
+        assert_eq!((scale.min_exponent, scale.max_exponent), (1, 10));
+        assert_eq!(scale.position(10.0), 0.0);
+        assert!((scale.position(1e3) - 2.0 / 9.0).abs() < 1e-9);
+        assert_eq!(scale.position(1e10), 1.0);
+
+        assert_eq!(decade_label(0), "1ns");
+        assert_eq!(decade_label(5), "100µs");
+        assert_eq!(decade_label(10), "10s");
+        assert_eq!(decade_label(12), "1000s");
+    }
+
+    #[test]
+    fn renders_runtime_chart() {
+        let timings = Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some(PartTiming::new(83_000.0, None)),
+                    part_2: None,
+                    total_nanos: 83_000.0,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0.0,
+                },
+            ],
+        };
+
+        let svg = runtime_svg("Benchmarks (2024)", &timings).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("<title>Day 1 part 1: 83.0µs</title>"));
+        assert!(!svg.contains("Day 2"));
+        assert_eq!(svg.matches("<rect").count(), 2);
+        assert!(runtime_svg("", &Timings::default()).is_none());
+    }
+}
@@ -1,5 +1,19 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::{process, time::Duration};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    answers::Answers,
+    run_multi::{run_multi, Mode},
+    summary, DaySet, Year,
+};
+
+pub fn handle(year: Year, days: &DaySet, mode: Mode, jobs: usize, timeout: Option<Duration>) {
+    let days_to_run = days.days(year).into_iter().collect();
+    let mut days = run_multi(year, &days_to_run, mode, false, jobs, timeout).days;
+
+    summary::check_answers(&mut days, &Answers::read_from_file(year));
+    summary::print(&days);
+
+    if summary::has_failures(&days) {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,95 @@
+use std::{
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    process,
+};
+
+use crate::template::{
+    examples, get_file_part_path, get_file_path,
+    registry::{self, Solution},
+    summary::{format_answer, format_table, print_table},
+    DaySet, PuzzleId, Year,
+};
+
+pub fn handle(year: Year, days: &DaySet, solutions: &[Solution]) {
+    let mut rows: Vec<[String; 6]> = vec![];
+    let mut failed = 0;
+
+    for day in days.days(year) {
+        let puzzle = PuzzleId::new(year, day);
+
+        let Some(solution) = registry::find(solutions, puzzle) else {
+            continue;
+        };
+
+        let Ok(source) = fs::read_to_string(puzzle.bin_path()) else {
+            continue;
+        };
+
+        let tests = examples::parse_tests(&source);
+
+        // NOTE: tests that do not follow the template would silently be missing from the table.
+        let unrecognized = examples::count_tests(&source).saturating_sub(tests.len());
+        if unrecognized > 0 {
+            eprintln!(
+                "Day {day}: {unrecognized} test(s) could not be matched to an example, expecting `let result = part_one(&read_file(\"examples\", DAY));` followed by `assert_eq!(result, ...)`."
+            );
+        }
+
+        for test in tests {
+            let path = match test.file_part {
+                Some(part) => get_file_part_path("examples", puzzle, part),
+                None => get_file_path("examples", puzzle),
+            };
+
+            let part = solution.parts.iter().find(|p| p.part == test.part);
+
+            let (actual, status) = match (fs::read_to_string(&path), part) {
+                (Err(_), _) => (None, "no example"),
+                (_, None) => (None, "no part"),
+                (Ok(input), Some(part)) => {
+                    match panic::catch_unwind(AssertUnwindSafe(|| {
+                        (part.run)(&input, false, &mut io::sink())
+                    })) {
+                        Ok(result) if result.answer == test.expected => (result.answer, "pass"),
+                        Ok(result) => (result.answer, "fail"),
+                        Err(_) => (None, "panicked"),
+                    }
+                }
+            };
+
+            if status != "pass" {
+                failed += 1;
+            }
+
+            rows.push([
+                day.to_string(),
+                test.name,
+                path.file_name()
+                    .map(|f| f.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                format_answer(test.expected.as_deref()),
+                format_answer(actual.as_deref()),
+                status.to_string(),
+            ]);
+        }
+    }
+
+    if rows.is_empty() {
+        println!("No example tests found.");
+        return;
+    }
+
+    let header = ["Day", "Test", "Example", "Expected", "Actual", "Status"].map(String::from);
+    print_table(format_table(&header, &rows));
+
+    println!(
+        "\n{} of {} examples passed, {failed} failed.",
+        rows.len() - failed,
+        rows.len()
+    );
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,44 @@
+use std::{path::Path, process};
+
+use crate::template::{heap_profile::HeapProfile, ANSI_BOLD, ANSI_RESET};
+
+/// The profile written by `solve --dhat`.
+pub const DEFAULT_PROFILE: &str = "dhat-heap.json";
+
+/// The number of allocation sites listed.
+const TOP_SITES: usize = 10;
+
+/// Prints a summary of a dhat heap profile, or its changes compared to a baseline profile.
+pub fn handle(path: &Path, baseline: Option<&Path>) {
+    let profile = read_or_exit(path);
+
+    let lines = match baseline {
+        Some(baseline) => {
+            println!(
+                "{ANSI_BOLD}Heap profile{ANSI_RESET} {} compared to {}",
+                path.display(),
+                baseline.display()
+            );
+            profile.format_comparison(&read_or_exit(baseline), TOP_SITES)
+        }
+        None => {
+            println!("{ANSI_BOLD}Heap profile{ANSI_RESET} {}", path.display());
+            profile.format_summary(TOP_SITES)
+        }
+    };
+
+    println!("------");
+    for line in lines {
+        println!("{line}");
+    }
+}
+
+fn read_or_exit(path: &Path) -> HeapProfile {
+    match HeapProfile::read_from_file(path) {
+        Ok(profile) => profile,
+        Err(e) => {
+            eprintln!("Failed to read heap profile: {e}");
+            process::exit(1);
+        }
+    }
+}
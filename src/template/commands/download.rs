@@ -1,14 +1,99 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::{aoc_client::AocClient, DaySet, PuzzleId, Year};
+use std::{fs, path::Path, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+/// Downloads the input and the puzzle description of a single day and exits if that fails.
+pub fn handle(puzzle: PuzzleId, force: bool) {
+    if let Err(e) = download_puzzle(puzzle, force) {
+        eprintln!("{e}");
         process::exit(1);
     }
+}
+
+/// Downloads the selected days. Days that fail are reported and skipped, the command exits with a
+/// non-zero status after trying every day.
+pub fn handle_days(year: Year, days: &DaySet, force: bool) {
+    let days = days.days(year);
+    let mut failed = vec![];
+
+    for day in &days {
+        if let Err(e) = download_puzzle(PuzzleId::new(year, *day), force) {
+            eprintln!("Day {day}: {e}");
+            failed.push(day.to_string());
+        }
+    }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if days.len() > 1 {
+        println!(
+            "🎄 Downloaded {} of {} days.",
+            days.len() - failed.len(),
+            days.len()
+        );
+    }
+
+    if !failed.is_empty() {
+        eprintln!("Failed to download day(s) {}.", failed.join(", "));
         process::exit(1);
-    };
+    }
+}
+
+/// Downloads the input and the puzzle description. Inputs never change, so a cached input is only
+/// downloaded again with `force`. The description is downloaded again until part two is unlocked.
+fn download_puzzle(puzzle: PuzzleId, force: bool) -> Result<(), String> {
+    let input_path = puzzle.data_path("inputs", "txt");
+    let puzzle_path = puzzle.data_path("puzzles", "md");
+
+    let input_cached = !force && is_cached(&input_path, is_valid_input);
+    let puzzle_cached = !force && is_cached(&puzzle_path, |md| md.contains("--- Part Two ---"));
+
+    if input_cached {
+        println!("🎄 Using cached input \"{input_path}\", append `--force` to download it again.");
+    }
+    if puzzle_cached {
+        println!(
+            "🎄 Using cached puzzle \"{puzzle_path}\", append `--force` to download it again."
+        );
+    }
+    if input_cached && puzzle_cached {
+        return Ok(());
+    }
+
+    let client = AocClient::from_env().map_err(|e| format!("Failed to download puzzle: {e}"))?;
+
+    if !input_cached {
+        download(&input_path, || client.input(puzzle))?;
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+
+    if !puzzle_cached {
+        download(&puzzle_path, || client.puzzle(puzzle))?;
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    }
+
+    Ok(())
+}
+
+fn download<E: std::fmt::Display>(
+    path: &str,
+    request: impl FnOnce() -> Result<String, E>,
+) -> Result<(), String> {
+    let contents = request().map_err(|e| format!("Failed to download \"{path}\": {e}"))?;
+    write_file(path, &contents).map_err(|e| format!("Failed to write \"{path}\": {e}"))
+}
+
+fn is_cached(path: &str, is_valid: impl Fn(&str) -> bool) -> bool {
+    fs::read_to_string(path).is_ok_and(|contents| is_valid(&contents))
+}
+
+/// Returns `false` for empty inputs, e.g. created by `scaffold`, and for error pages of the puzzle server.
+fn is_valid_input(input: &str) -> bool {
+    !input.trim().is_empty()
+        && !input.starts_with("Puzzle inputs differ by user")
+        && !input.starts_with("Please don't repeatedly request")
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
 }
@@ -0,0 +1,182 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::template::{
+    examples::{parse_tests, update_tests},
+    extraction::{self, Extraction},
+    get_file_part_path, get_file_path, PuzzleId, Year,
+};
+
+/// What writing an extracted example file would do.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Create,
+    Overwrite,
+    Unchanged,
+}
+
+impl Change {
+    fn of(path: &Path, contents: &str) -> Self {
+        match fs::read_to_string(path) {
+            Ok(existing) if existing == contents => Change::Unchanged,
+            Ok(existing) if existing.trim().is_empty() => Change::Create,
+            Ok(_) => Change::Overwrite,
+            Err(_) => Change::Create,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Change::Create => "create",
+            Change::Overwrite => "overwrite",
+            Change::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// Reads the downloaded puzzle description and extracts its examples, if it has been downloaded.
+pub(super) fn extract(puzzle: PuzzleId) -> Option<Extraction> {
+    let markdown = fs::read_to_string(puzzle.data_path("puzzles", "md")).ok()?;
+    Some(extraction::extract(&markdown))
+}
+
+/// Returns the example files of an extraction, with a trailing newline like the downloaded inputs.
+pub(super) fn example_files(puzzle: PuzzleId, extraction: &Extraction) -> Vec<(PathBuf, String)> {
+    extraction
+        .files()
+        .into_iter()
+        .map(|(part, input)| {
+            let path = match part {
+                Some(part) => get_file_part_path("examples", puzzle, part),
+                None => get_file_path("examples", puzzle),
+            };
+            // NOTE: paths are printed, so keep them relative to the project.
+            let path = env::current_dir()
+                .ok()
+                .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+                .unwrap_or(path);
+            (path, format!("{input}\n"))
+        })
+        .collect()
+}
+
+/// Writes the example files that do not exist yet or are empty. Returns the paths of the files that were written.
+pub(super) fn write_missing(files: &[(PathBuf, String)]) -> Result<Vec<PathBuf>, std::io::Error> {
+    let mut written = vec![];
+
+    for (path, contents) in files {
+        if Change::of(path, contents) == Change::Create {
+            fs::write(path, contents)?;
+            written.push(path.clone());
+        }
+    }
+
+    Ok(written)
+}
+
+/// Prints the examples extracted from the puzzle description and the changes they imply.
+/// Nothing is changed unless `write` is set, in which case the example files are overwritten
+/// and the example tests of the solution are updated.
+pub fn handle(puzzle: PuzzleId, write: bool) {
+    let Some(extraction) = extract(puzzle) else {
+        eprintln!(
+            "Puzzle description \"{}\" not found, run `cargo download {}` first.",
+            puzzle.data_path("puzzles", "md"),
+            puzzle.day
+        );
+        process::exit(1);
+    };
+
+    let files = example_files(puzzle, &extraction);
+    if files.is_empty() {
+        println!("No example found in the puzzle description of {puzzle}.");
+    }
+
+    for (path, contents) in &files {
+        println!(
+            "{} ({}):",
+            path.display(),
+            Change::of(path, contents).label()
+        );
+        for line in contents.lines() {
+            println!("  {line}");
+        }
+    }
+
+    for (part, answer) in extraction.answers().iter().enumerate() {
+        match answer {
+            Some(answer) => println!("Part {}: expecting {answer}", part + 1),
+            None => println!("Part {}: no answer found", part + 1),
+        }
+    }
+
+    let module_path = puzzle.bin_path();
+    let source = fs::read_to_string(&module_path).ok();
+    let updated = source
+        .as_deref()
+        .map(|source| update_tests(source, &extraction.answers(), extraction.is_split()));
+
+    if let (Some(source), Some(updated)) = (&source, &updated) {
+        let before = parse_tests(source);
+        let after = parse_tests(updated);
+
+        for (old, new) in before.iter().zip(&after).filter(|(old, new)| old != new) {
+            println!(
+                "{module_path}: {} expects {} instead of {}",
+                new.name,
+                format_test(new.expected.as_deref(), new.file_part),
+                format_test(old.expected.as_deref(), old.file_part)
+            );
+        }
+    }
+
+    if !write {
+        let year_arg = if Year::from_env() == Some(puzzle.year) {
+            String::new()
+        } else {
+            format!(" --year {}", puzzle.year)
+        };
+        println!("---");
+        println!(
+            "Review the examples and type `cargo examples {} --write{year_arg}` to apply them.",
+            puzzle.day
+        );
+        return;
+    }
+
+    for (path, contents) in &files {
+        if Change::of(path, contents) == Change::Unchanged {
+            continue;
+        }
+        if let Err(e) = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(path, contents))
+        {
+            eprintln!("Failed to write example file \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+        println!("Wrote example file \"{}\"", path.display());
+    }
+
+    if let (Some(source), Some(updated)) = (source, updated) {
+        if source != updated {
+            if let Err(e) = fs::write(&module_path, updated) {
+                eprintln!("Failed to update tests in \"{module_path}\": {e}");
+                process::exit(1);
+            }
+            println!("Updated tests in \"{module_path}\"");
+        }
+    }
+}
+
+fn format_test(expected: Option<&str>, file_part: Option<u8>) -> String {
+    let expected = expected.unwrap_or("None");
+    match file_part {
+        Some(part) => format!("{expected} (example {part})"),
+        None => expected.to_string(),
+    }
+}
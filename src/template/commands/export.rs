@@ -0,0 +1,350 @@
+use std::{collections::HashMap, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    all_days,
+    answers::Answers,
+    aoc_client::Verdict,
+    submissions::Submissions,
+    timings::{PartTiming, Timings},
+    Day, DaySet, Year,
+};
+
+/// The columns of an export, in order. Rows are parts, so timings and answers of several years can be appended.
+const COLUMNS: [&str; 11] = [
+    "year",
+    "day",
+    "part",
+    "answer",
+    "nanos",
+    "samples",
+    "parse_nanos",
+    "memory_bytes",
+    "submissions",
+    "verdict",
+    "solved_at",
+];
+
+/// The formats `export` can write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Markdown,
+    Json,
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(ExportFormat::Csv),
+            "md" => Ok(ExportFormat::Markdown),
+            "json" => Ok(ExportFormat::Json),
+            _ => Err(format!(
+                "unknown export format `{s}`, expected `csv`, `md` or `json`"
+            )),
+        }
+    }
+}
+
+/// Everything that is known about a single part.
+struct Row {
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: Option<String>,
+    timing: Option<PartTiming>,
+    submissions: usize,
+    /// The verdict of the latest submission.
+    verdict: Option<Verdict>,
+    /// The time of the first correct submission, in seconds since the unix epoch.
+    solved_at: Option<u64>,
+}
+
+/// Prints the timings, answers and submissions of the selected days, or of all days, to stdout.
+pub fn handle(year: Year, days: Option<DaySet>, format: ExportFormat) {
+    let days: Vec<Day> = match days {
+        Some(days) => days.days(year).into_iter().collect(),
+        None => all_days(year).collect(),
+    };
+
+    let rows = collect(
+        year,
+        &days,
+        &super::read_timings_or_exit(year),
+        &Answers::read_from_file(year),
+        &Submissions::read_from_file(year),
+    );
+
+    match format {
+        ExportFormat::Csv => print!("{}", to_csv(&rows)),
+        ExportFormat::Markdown => print!("{}", to_markdown(&rows)),
+        ExportFormat::Json => match to_json(&rows).format() {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Failed to format export: {e}");
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+/// Returns a row for every part of the days that has an answer, a timing or a submission.
+fn collect(
+    year: Year,
+    days: &[Day],
+    timings: &Timings,
+    answers: &Answers,
+    submissions: &Submissions,
+) -> Vec<Row> {
+    days.iter()
+        .flat_map(|day| [1, 2].map(|part| (*day, part)))
+        .map(|(day, part)| {
+            let correct = submissions
+                .of_part(day, part)
+                .find(|s| s.verdict == Verdict::Correct);
+
+            Row {
+                year,
+                day,
+                part,
+                answer: answers
+                    .get(day, part)
+                    .map(String::from)
+                    .or_else(|| correct.map(|s| s.answer.clone())),
+                timing: timings.get(day).and_then(|t| t.part(part)).copied(),
+                submissions: submissions.of_part(day, part).count(),
+                verdict: submissions.of_part(day, part).last().map(|s| s.verdict),
+                solved_at: correct.map(|s| s.timestamp),
+            }
+        })
+        .filter(|row| row.answer.is_some() || row.timing.is_some() || row.submissions > 0)
+        .collect()
+}
+
+/// Formats the fields of a row as text, empty fields are `None`.
+fn cells(row: &Row) -> [Option<String>; 11] {
+    let timing = row.timing.as_ref();
+    [
+        Some(row.year.to_string()),
+        Some(row.day.to_string()),
+        Some(row.part.to_string()),
+        row.answer.clone(),
+        timing.map(|t| t.nanos.to_string()),
+        timing.and_then(|t| t.samples).map(|s| s.to_string()),
+        timing.and_then(|t| t.parse_nanos).map(|n| n.to_string()),
+        timing.and_then(|t| t.memory_bytes).map(|b| b.to_string()),
+        Some(row.submissions.to_string()),
+        row.verdict.map(|v| v.to_string()),
+        row.solved_at.map(format_timestamp),
+    ]
+}
+
+fn to_csv(rows: &[Row]) -> String {
+    let escape = |field: &str| {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    };
+
+    let mut lines = vec![COLUMNS.join(",")];
+    for row in rows {
+        let fields: Vec<String> = cells(row)
+            .iter()
+            .map(|cell| cell.as_deref().map(escape).unwrap_or_default())
+            .collect();
+        lines.push(fields.join(","));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn to_markdown(rows: &[Row]) -> String {
+    let escape = |field: &str| field.replace('|', "\\|").replace('\n', "<br>");
+
+    let mut lines = vec![
+        format!("| {} |", COLUMNS.join(" | ")),
+        format!("|{}", " --- |".repeat(COLUMNS.len())),
+    ];
+    for row in rows {
+        let fields: Vec<String> = cells(row)
+            .iter()
+            .map(|cell| cell.as_deref().map_or_else(|| "-".into(), escape))
+            .collect();
+        lines.push(format!("| {} |", fields.join(" | ")));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn to_json(rows: &[Row]) -> JsonValue {
+    let number = |n: Option<f64>| n.map_or(JsonValue::Null, JsonValue::Number);
+    let string = |s: Option<String>| s.map_or(JsonValue::Null, JsonValue::String);
+
+    let rows: Vec<JsonValue> = rows
+        .iter()
+        .map(|row| {
+            let timing = row.timing.as_ref();
+            let values = [
+                number(Some(f64::from(row.year.into_inner()))),
+                string(Some(row.day.to_string())),
+                number(Some(f64::from(row.part))),
+                string(row.answer.clone()),
+                number(timing.map(|t| t.nanos)),
+                number(timing.and_then(|t| t.samples).map(|s| s as f64)),
+                number(timing.and_then(|t| t.parse_nanos)),
+                number(timing.and_then(|t| t.memory_bytes).map(|b| b as f64)),
+                number(Some(row.submissions as f64)),
+                string(row.verdict.map(|v| v.to_string())),
+                string(row.solved_at.map(format_timestamp)),
+            ];
+
+            let map: HashMap<String, JsonValue> = COLUMNS
+                .iter()
+                .map(|column| (*column).to_string())
+                .zip(values)
+                .collect();
+            JsonValue::Object(map)
+        })
+        .collect();
+
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert("version".into(), JsonValue::Number(1.0));
+    map.insert("rows".into(), JsonValue::Array(rows));
+    JsonValue::Object(map)
+}
+
+/// Formats seconds since the unix epoch as an ISO 8601 date in UTC, e.g. `2024-12-01T05:00:00Z`.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // NOTE: converts days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{collect, format_timestamp, to_csv, to_json, to_markdown, Row};
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            aoc_client::Verdict,
+            submissions::{SubmissionRecord, Submissions},
+            timings::{PartTiming, Timing, Timings},
+            Year,
+        },
+    };
+
+    fn rows() -> Vec<Row> {
+        let year = Year::new(2024).unwrap();
+
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some(PartTiming::new(1500.0, Some(100))),
+                part_2: None,
+                total_nanos: 1500.0,
+            }],
+        };
+
+        let mut answers = Answers::default();
+        answers.set(day!(2), 1, "#.\n.#".into());
+
+        let submissions = Submissions {
+            data: vec![
+                SubmissionRecord {
+                    day: day!(1),
+                    part: 1,
+                    answer: "1,2".into(),
+                    verdict: Verdict::Incorrect,
+                    timestamp: 1_733_029_200,
+                },
+                SubmissionRecord {
+                    day: day!(1),
+                    part: 1,
+                    answer: "1,3".into(),
+                    verdict: Verdict::Correct,
+                    timestamp: 1_733_029_500,
+                },
+            ],
+        };
+
+        collect(
+            year,
+            &[day!(1), day!(2), day!(3)],
+            &timings,
+            &answers,
+            &submissions,
+        )
+    }
+
+    #[test]
+    fn exports_csv() {
+        assert_eq!(
+            to_csv(&rows()),
+            "year,day,part,answer,nanos,samples,parse_nanos,memory_bytes,submissions,verdict,solved_at
+2024,01,1,\"1,3\",1500,100,,,2,correct,2024-12-01T05:05:00Z
+2024,02,1,\"#.
+.#\",,,,,0,,
+"
+        );
+    }
+
+    #[test]
+    fn exports_markdown() {
+        let markdown = to_markdown(&rows());
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[3],
+            "| 2024 | 02 | 1 | #.<br>.# | - | - | - | - | 0 | - | - |"
+        );
+    }
+
+    #[test]
+    fn exports_json() {
+        let json = to_json(&rows());
+        let row = &json["rows"][0];
+        assert_eq!(row["nanos"], tinyjson::JsonValue::Number(1500.0));
+        assert_eq!(row["parse_nanos"], tinyjson::JsonValue::Null);
+        assert_eq!(row["memory_bytes"], tinyjson::JsonValue::Null);
+        assert_eq!(
+            row["verdict"],
+            tinyjson::JsonValue::String("correct".into())
+        );
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(1_733_029_200), "2024-12-01T05:00:00Z");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+    }
+}
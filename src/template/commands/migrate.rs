@@ -0,0 +1,110 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{all_days, manifest, readme_benchmarks, PuzzleId, Year};
+
+const DATA_FOLDERS: [&str; 3] = ["inputs", "examples", "puzzles"];
+const DATA_FILES: [&str; 2] = ["timings.json", "answers.json"];
+
+/// Moves solutions and data of the single-year layout (`src/bin/<day>.rs`, `data/<folder>/<day>.txt`)
+/// into the folders of a year (`src/bin/<year>/<day>.rs`, `data/<year>/<folder>/<day>.txt`).
+pub fn handle(year: Year) {
+    let mut moved = 0;
+
+    for day in all_days(year) {
+        let puzzle = PuzzleId::new(year, day);
+        let from = format!("src/bin/{day}.rs");
+
+        if !Path::new(&from).exists() {
+            continue;
+        }
+
+        if !move_file(&from, &puzzle.bin_path()) {
+            continue;
+        }
+
+        moved += 1;
+
+        if let Err(e) = manifest::add_bin(puzzle) {
+            eprintln!("Failed to add binary to \"Cargo.toml\": {e}");
+            process::exit(1);
+        }
+    }
+
+    for folder in DATA_FOLDERS {
+        let from_folder = format!("data/{folder}");
+
+        let Ok(entries) = fs::read_dir(&from_folder) else {
+            continue;
+        };
+
+        let mut names: Vec<String> = entries
+            .flatten()
+            .filter(|entry| entry.path().is_file())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect();
+
+        names.sort();
+
+        for name in names {
+            if move_file(
+                &format!("{from_folder}/{name}"),
+                &format!("data/{year}/{folder}/{name}"),
+            ) {
+                moved += 1;
+            }
+        }
+
+        // NOTE: only removes the folder if it is empty.
+        let _ = fs::remove_dir(&from_folder);
+    }
+
+    let mut has_timings = false;
+
+    for file in DATA_FILES {
+        let from = format!("data/{file}");
+
+        if Path::new(&from).exists() && move_file(&from, &format!("data/{year}/{file}")) {
+            has_timings |= file == "timings.json";
+            moved += 1;
+        }
+    }
+
+    if moved == 0 {
+        println!("Nothing to migrate.");
+        return;
+    }
+
+    // links in the benchmark table point to the solutions.
+    if has_timings {
+        if let Err(e) = readme_benchmarks::update(year, super::read_timings_or_exit(year)) {
+            eprintln!("Failed to update benchmarks in README: {e}");
+        }
+    }
+
+    println!("---");
+    println!("🎄 Migrated {moved} files to {year}.");
+}
+
+/// Moves a file, creating missing folders. Returns `false` if the destination already exists.
+fn move_file(from: &str, to: &str) -> bool {
+    if Path::new(to).exists() {
+        eprintln!("Skipping \"{from}\", \"{to}\" already exists.");
+        return false;
+    }
+
+    let result = Path::new(to)
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::rename(from, to));
+
+    match result {
+        Ok(()) => {
+            println!("Moved \"{from}\" to \"{to}\"");
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to move \"{from}\" to \"{to}\": {e}");
+            process::exit(1);
+        }
+    }
+}
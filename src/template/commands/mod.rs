@@ -1,6 +1,25 @@
 pub mod all;
+pub mod check_examples;
+pub mod dhat;
 pub mod download;
+pub mod examples;
+pub mod export;
+pub mod migrate;
 pub mod read;
+pub mod readme;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
 pub mod time;
+
+use std::process;
+
+use crate::template::{timings::Timings, Year};
+
+/// Reads the stored timings of a year, exiting if the timings file cannot be read.
+fn read_timings_or_exit(year: Year) -> Timings {
+    Timings::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read timings: {e}");
+        process::exit(1);
+    })
+}
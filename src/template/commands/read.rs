@@ -1,15 +1,40 @@
-use std::process;
+use std::{fs, path::Path, process};
 
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_client::AocClient, markdown, PuzzleId, ANSI_ITALIC, ANSI_RESET};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+/// Renders the puzzle description that was stored by `download`, it is only fetched if it was not downloaded yet.
+pub fn handle(puzzle: PuzzleId) {
+    let path = puzzle.data_path("puzzles", "md");
+
+    let description = match fs::read_to_string(&path) {
+        Ok(description) if !description.trim().is_empty() => description,
+        _ => fetch(puzzle, &path),
+    };
+
+    print!("{}", markdown::render(&description));
+
+    if !description.contains("--- Part Two ---") {
+        println!(
+            "\n{ANSI_ITALIC}Part two is shown once it is unlocked and the puzzle was downloaded again with `cargo download {}`.{ANSI_RESET}",
+            puzzle.day
+        );
     }
+}
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+fn fetch(puzzle: PuzzleId, path: &str) -> String {
+    let description = match AocClient::from_env().and_then(|client| client.puzzle(puzzle)) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("Failed to read puzzle: {e}");
+            process::exit(1);
+        }
     };
+
+    // NOTE: caching the description is a courtesy, reading it does not depend on it.
+    if let Some(parent) = Path::new(path).parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let _ = fs::write(path, &description);
+
+    description
 }
@@ -0,0 +1,21 @@
+use std::process;
+
+use crate::template::{readme_benchmarks, readme_stars, Year};
+
+/// Regenerates the stars and benchmark tables in the readme from the data stored for the year.
+pub fn handle(year: Year) {
+    if let Err(e) = readme_stars::update(year) {
+        eprintln!("Failed to update stars in README: {e}");
+        process::exit(1);
+    }
+
+    let timings = super::read_timings_or_exit(year);
+    if !timings.data.is_empty() {
+        if let Err(e) = readme_benchmarks::update(year, timings) {
+            eprintln!("Failed to update benchmarks in README: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("Updated README for {year}.");
+}
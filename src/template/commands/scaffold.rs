@@ -1,13 +1,16 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
     process,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::{
+    commands::examples,
+    examples::update_tests,
+    manifest,
+    templates::{self, Placeholders},
+    PuzzleId, Year,
+};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,18 +22,49 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Creates an empty file unless it exists already. Returns `false` if the file exists.
+fn create_file(path: &str) -> Result<bool, std::io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: PuzzleId, overwrite: bool, template: &str) {
+    let source = match templates::find(template) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let mut placeholders = Placeholders::new(puzzle);
+    placeholders.title = fs::read_to_string(puzzle.data_path("puzzles", "md"))
+        .ok()
+        .and_then(|puzzle| templates::parse_title(&puzzle));
+
+    // NOTE: if the puzzle has been downloaded, the examples and their answers are extracted from it.
+    let extraction = examples::extract(puzzle);
+    if let Some(extraction) = &extraction {
+        placeholders.example_answers = extraction.answers();
+    }
+
+    let input_path = puzzle.data_path("inputs", "txt");
+    let example_path = puzzle.data_path("examples", "txt");
+    let module_path = puzzle.bin_path();
+
+    for folder in [
+        format!("src/bin/{}", puzzle.year),
+        format!("data/{}/inputs", puzzle.year),
+        format!("data/{}/examples", puzzle.year),
+    ] {
+        if let Err(e) = fs::create_dir_all(&folder) {
+            eprintln!("Failed to create folder \"{folder}\": {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +74,12 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let mut contents = templates::render(&source, &placeholders);
+    if extraction.as_ref().is_some_and(|e| e.is_split()) {
+        contents = update_tests(&contents, &placeholders.example_answers, true);
+    }
+
+    match file.write_all(contents.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -55,25 +90,78 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&input_path) {
-        Ok(_) => {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {}
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    let example_files = extraction
+        .as_ref()
+        .map(|extraction| examples::example_files(puzzle, extraction))
+        .unwrap_or_default();
+
+    if example_files.is_empty() {
+        match create_file(&example_path) {
+            Ok(true) => {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
+        }
+    } else {
+        match examples::write_missing(&example_files) {
+            Ok(written) => {
+                for path in written {
+                    println!(
+                        "Created example file \"{}\" from the puzzle",
+                        path.display()
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
         }
+        println!(
+            "Extracted example answers: {}",
+            placeholders
+                .example_answers
+                .iter()
+                .map(|answer| answer.as_deref().unwrap_or("?"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    match manifest::add_bin(puzzle) {
+        Ok(true) => {
+            println!("Added binary \"{puzzle}\" to \"Cargo.toml\"");
+        }
+        Ok(false) => {}
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("Failed to add binary to \"Cargo.toml\": {e}");
             process::exit(1);
         }
     }
 
+    let year_arg = if Year::from_env() == Some(puzzle.year) {
+        String::new()
+    } else {
+        format!(" --year {}", puzzle.year)
+    };
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!(
+        "🎄 Type `cargo solve {}{year_arg}` to run your solution.",
+        puzzle.day
+    );
 }
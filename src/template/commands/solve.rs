@@ -1,9 +1,22 @@
-use std::process::{Command, Stdio};
+use std::{
+    fs,
+    io::{stdout, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
 
-use crate::template::Day;
+use crate::template::{
+    commands::dhat::{self, DEFAULT_PROFILE},
+    get_file_path, PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+fn build_args(puzzle: PuzzleId, release: bool, dhat: bool, submit_part: Option<u8>) -> Vec<String> {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -23,6 +36,12 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    cmd_args
+}
+
+pub fn handle(puzzle: PuzzleId, release: bool, dhat: bool, submit_part: Option<u8>) {
+    let cmd_args = build_args(puzzle, release, dhat, submit_part);
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -30,5 +49,189 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    let status = cmd.wait().unwrap();
+
+    // NOTE: a failed run leaves the profile of a previous run behind.
+    if dhat && status.success() {
+        println!();
+        dhat::handle(Path::new(DEFAULT_PROFILE), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Re-runs the solution (or its example tests) whenever one of its source, input or example files changes.
+/// The output of every run is diffed against the output of the previous run.
+pub fn watch(puzzle: PuzzleId, release: bool, test: bool) {
+    let cmd_args = if test {
+        vec!["test".to_string(), "--bin".to_string(), puzzle.to_string()]
+    } else {
+        build_args(puzzle, release, false, None)
+    };
+
+    let mut snapshot = vec![];
+    let mut previous: Option<Vec<String>> = None;
+
+    loop {
+        let current = watched_files(puzzle)
+            .into_iter()
+            .map(|path| fs::metadata(&path).and_then(|m| m.modified()).ok())
+            .collect::<Vec<Option<SystemTime>>>();
+
+        if current != snapshot {
+            snapshot = current;
+
+            print!("{ANSI_CLEAR_SCREEN}");
+            println!(
+                "{ANSI_BOLD}Day {}{ANSI_RESET} {ANSI_ITALIC}(cargo {}){ANSI_RESET}",
+                puzzle.day,
+                cmd_args.join(" ")
+            );
+            println!("------");
+            let _ = stdout().flush();
+
+            let output = run_captured(&cmd_args);
+
+            for line in diff_lines(previous.as_deref(), &output) {
+                println!("{line}");
+            }
+
+            println!("---");
+            println!("🎄 Watching for changes. Press Ctrl-C to stop.");
+
+            previous = Some(output);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn watched_files(puzzle: PuzzleId) -> Vec<PathBuf> {
+    let day = puzzle.day;
+
+    let mut files = vec![
+        PathBuf::from(puzzle.bin_path()),
+        PathBuf::from("src/lib.rs"),
+        PathBuf::from("src/arena.rs"),
+        get_file_path("inputs", puzzle),
+    ];
+
+    // include example files with a part suffix, e.g. `03-1.txt`.
+    let mut examples: Vec<PathBuf> = fs::read_dir(format!("data/{}/examples", puzzle.year))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem == day.to_string() || stem.starts_with(&format!("{day}-")))
+        })
+        .collect();
+
+    examples.sort();
+    files.extend(examples);
+    files
+}
+
+/// Runs cargo with the given arguments, forwarding stderr and capturing stdout.
+fn run_captured(cmd_args: &[String]) -> Vec<String> {
+    let Ok(mut cmd) = Command::new("cargo")
+        .args(cmd_args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+    else {
+        eprintln!("Failed to spawn cargo.");
+        return vec![];
+    };
+
+    let output = cmd
+        .stdout
+        .take()
+        .map(|stdout| {
+            BufReader::new(stdout)
+                .lines()
+                .map_while(Result::ok)
+                // NOTE: intermediate results are overwritten with a carriage return.
+                .map(|line| line.rsplit('\r').next().unwrap_or_default().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let _ = cmd.wait();
+    output
+}
+
+/// Strips durations from part results and test summaries, which differ between every run.
+fn normalize(line: &str) -> &str {
+    if line.starts_with("Part ") {
+        line.rsplit_once(" (").map_or(line, |(result, _)| result)
+    } else if line.starts_with("test result:") {
+        line.split_once("; finished in")
+            .map_or(line, |(result, _)| result)
+    } else {
+        line
+    }
+}
+
+/// Marks lines that changed compared to the previous run with `-` / `+`.
+fn diff_lines(previous: Option<&[String]>, current: &[String]) -> Vec<String> {
+    let Some(previous) = previous else {
+        return current.iter().map(|line| format!("  {line}")).collect();
+    };
+
+    let mut lines = vec![];
+
+    for i in 0..previous.len().max(current.len()) {
+        match (previous.get(i), current.get(i)) {
+            (Some(a), Some(b)) if normalize(a) == normalize(b) => lines.push(format!("  {b}")),
+            (a, b) => {
+                if let Some(a) = a {
+                    lines.push(format!("- {a}"));
+                }
+                if let Some(b) = b {
+                    lines.push(format!("{ANSI_BOLD}+ {b}{ANSI_RESET}"));
+                }
+            }
+        }
+    }
+
+    lines
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::diff_lines;
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    #[test]
+    fn shows_first_run_without_markers() {
+        let current = vec!["Part 1: 42 (1.0ms)".to_string()];
+        assert_eq!(diff_lines(None, &current), ["  Part 1: 42 (1.0ms)"]);
+    }
+
+    #[test]
+    fn ignores_changed_durations() {
+        let previous = vec!["Part 1: 42 (1.0ms)".to_string()];
+        let current = vec!["Part 1: 42 (2.0ms)".to_string()];
+        assert_eq!(
+            diff_lines(Some(&previous), &current),
+            ["  Part 1: 42 (2.0ms)"]
+        );
+    }
+
+    #[test]
+    fn marks_changed_lines() {
+        let previous = vec!["Part 1: 42 (1.0ms)".to_string(), "Part 2: ✖".to_string()];
+        let current = vec!["Part 1: 43 (1.0ms)".to_string()];
+        assert_eq!(
+            diff_lines(Some(&previous), &current),
+            [
+                "- Part 1: 42 (1.0ms)".to_string(),
+                format!("{ANSI_BOLD}+ Part 1: 43 (1.0ms){ANSI_RESET}"),
+                "- Part 2: ✖".to_string(),
+            ]
+        );
+    }
 }
@@ -0,0 +1,264 @@
+use std::{collections::HashMap, fmt::Display, fs, path::Path};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    all_days,
+    answers::Answers,
+    get_file_part_path, get_file_path,
+    summary::{format_table, print_table},
+    Day, PuzzleId, Year, ANSI_BOLD, ANSI_RESET,
+};
+
+/// The progress of a single part. A part is solved if its answer is known and benched if it has a stored benchmark.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PartState {
+    Unsolved,
+    Solved,
+    Benched,
+}
+
+impl Display for PartState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartState::Unsolved => f.write_str("unsolved"),
+            PartState::Solved => f.write_str("solved"),
+            PartState::Benched => f.write_str("benched"),
+        }
+    }
+}
+
+/// The progress of a single day.
+#[derive(Clone, Debug)]
+struct DayStatus {
+    day: Day,
+    scaffolded: bool,
+    input: bool,
+    examples: bool,
+    parts: [PartState; 2],
+    total_nanos: Option<f64>,
+}
+
+impl DayStatus {
+    fn stars(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|p| **p != PartState::Unsolved)
+            .count()
+    }
+
+    fn is_started(&self) -> bool {
+        self.scaffolded || self.input || self.stars() > 0
+    }
+}
+
+/// Prints the progress of every day of a year, or writes it as JSON with `json`.
+pub fn handle(year: Year, json: bool) {
+    let days = collect(year);
+
+    if json {
+        match to_json(year, &days).format() {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Failed to format status: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    println!("{ANSI_BOLD}Advent of Code {year}{ANSI_RESET}\n");
+    format_calendar(&days)
+        .iter()
+        .for_each(|line| println!("{line}"));
+
+    let rows: Vec<[String; 6]> = days
+        .iter()
+        .filter(|d| d.is_started())
+        .map(|d| {
+            [
+                d.day.to_string(),
+                d.parts[0].to_string(),
+                d.parts[1].to_string(),
+                if d.input { "ok" } else { "missing" }.into(),
+                if d.examples { "ok" } else { "missing" }.into(),
+                d.total_nanos.map_or_else(|| "-".into(), format_millis),
+            ]
+        })
+        .collect();
+
+    if !rows.is_empty() {
+        println!();
+        let header = ["Day", "Part 1", "Part 2", "Input", "Examples", "Time"].map(String::from);
+        print_table(format_table(&header, &rows));
+    }
+
+    let stars: usize = days.iter().map(DayStatus::stars).sum();
+    let total_nanos = days
+        .iter()
+        .filter_map(|d| d.total_nanos)
+        .fold(0.0, |total, nanos| total + nanos);
+    println!(
+        "\n⭐ {stars} of {} stars, {} of {} days scaffolded, total runtime {}.",
+        days.len() * 2,
+        days.iter().filter(|d| d.scaffolded).count(),
+        days.len(),
+        format_millis(total_nanos)
+    );
+}
+
+fn collect(year: Year) -> Vec<DayStatus> {
+    let timings = super::read_timings_or_exit(year);
+    let answers = Answers::read_from_file(year);
+
+    all_days(year)
+        .map(|day| {
+            let puzzle = PuzzleId::new(year, day);
+            let timing = timings.data.iter().find(|t| t.day == day);
+
+            let parts = [1, 2].map(|part| {
+                let benched = timing.is_some_and(|t| t.part(part).is_some());
+
+                if benched {
+                    PartState::Benched
+                } else if answers.get(day, part).is_some() {
+                    PartState::Solved
+                } else {
+                    PartState::Unsolved
+                }
+            });
+
+            DayStatus {
+                day,
+                scaffolded: Path::new(&puzzle.bin_path()).exists(),
+                input: has_content(&get_file_path("inputs", puzzle)),
+                examples: has_content(&get_file_path("examples", puzzle))
+                    || has_content(&get_file_part_path("examples", puzzle, 1)),
+                parts,
+                total_nanos: timing.map(|t| t.total_nanos),
+            }
+        })
+        .collect()
+}
+
+fn has_content(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|contents| !contents.trim().is_empty())
+}
+
+fn format_millis(nanos: f64) -> String {
+    format!("{:.2}ms", nanos / 1_000_000_f64)
+}
+
+/// Formats the days as a calendar with five days per row, e.g. `01 ★★  02 ★☆  03 ··`.
+fn format_calendar(days: &[DayStatus]) -> Vec<String> {
+    days.chunks(5)
+        .map(|week| {
+            week.iter()
+                .map(|d| {
+                    let stars: String = d
+                        .parts
+                        .iter()
+                        .map(|p| match (p, d.scaffolded) {
+                            (PartState::Unsolved, false) => '·',
+                            (PartState::Unsolved, true) => '☆',
+                            _ => '★',
+                        })
+                        .collect();
+                    format!("{} {stars}", d.day)
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+        })
+        .collect()
+}
+
+fn to_json(year: Year, days: &[DayStatus]) -> JsonValue {
+    let days: Vec<JsonValue> = days
+        .iter()
+        .map(|d| {
+            let mut map: HashMap<String, JsonValue> = HashMap::new();
+            map.insert("day".into(), JsonValue::String(d.day.to_string()));
+            map.insert("scaffolded".into(), JsonValue::Boolean(d.scaffolded));
+            map.insert("input".into(), JsonValue::Boolean(d.input));
+            map.insert("examples".into(), JsonValue::Boolean(d.examples));
+            map.insert("stars".into(), JsonValue::Number(d.stars() as f64));
+            map.insert(
+                "parts".into(),
+                JsonValue::Array(
+                    d.parts
+                        .iter()
+                        .map(|p| JsonValue::String(p.to_string()))
+                        .collect(),
+                ),
+            );
+            map.insert(
+                "total_nanos".into(),
+                d.total_nanos.map_or(JsonValue::Null, JsonValue::Number),
+            );
+            JsonValue::Object(map)
+        })
+        .collect();
+
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert(
+        "year".into(),
+        JsonValue::Number(f64::from(year.into_inner())),
+    );
+    map.insert("days".into(), JsonValue::Array(days));
+    JsonValue::Object(map)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_calendar, to_json, DayStatus, PartState};
+    use crate::{day, template::Year};
+
+    fn statuses() -> Vec<DayStatus> {
+        vec![
+            DayStatus {
+                day: day!(1),
+                scaffolded: true,
+                input: true,
+                examples: true,
+                parts: [PartState::Benched, PartState::Solved],
+                total_nanos: Some(1_500_000.0),
+            },
+            DayStatus {
+                day: day!(2),
+                scaffolded: true,
+                input: false,
+                examples: false,
+                parts: [PartState::Solved, PartState::Unsolved],
+                total_nanos: None,
+            },
+            DayStatus {
+                day: day!(3),
+                scaffolded: false,
+                input: false,
+                examples: false,
+                parts: [PartState::Unsolved, PartState::Unsolved],
+                total_nanos: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn formats_calendar() {
+        assert_eq!(format_calendar(&statuses()), ["01 ★★  02 ★☆  03 ··"]);
+    }
+
+    #[test]
+    fn formats_json() {
+        let json = to_json(Year::new(2024).unwrap(), &statuses());
+
+        assert_eq!(json["year"], tinyjson::JsonValue::Number(2024.0));
+        let day = &json["days"][1];
+        assert_eq!(day["day"], tinyjson::JsonValue::String("02".into()));
+        assert_eq!(day["stars"], tinyjson::JsonValue::Number(1.0));
+        assert_eq!(day["input"], tinyjson::JsonValue::Boolean(false));
+        assert_eq!(
+            day["parts"][1],
+            tinyjson::JsonValue::String("unsolved".into())
+        );
+    }
+}
@@ -1,40 +1,427 @@
-use std::collections::HashSet;
+use crate::template::answers::Answers;
+use crate::template::report;
+use crate::template::run_multi::{run_multi, Mode};
+use crate::template::summary::{self, format_table, print_table, DayOutcome, Status};
+use crate::template::timing_history::TimingHistory;
+use crate::template::timings::{format_nanos, Timings};
+use crate::template::{
+    all_days, git, readme_benchmarks, readme_stars, Day, DaySet, Year, ANSI_BOLD, ANSI_GREEN,
+    ANSI_RED, ANSI_RESET,
+};
+use std::{collections::HashSet, process, str::FromStr};
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+/// Changes within this percentage are considered noise and are not highlighted.
+const NOISE_PERCENT: f64 = 5.0;
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// The number of times each day is benched by `check`, the fastest run counts.
+const CHECK_RUNS: usize = 3;
 
-    let days_to_run = day.map_or_else(
+/// The formats `time --report` can write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Html,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "html" => Ok(ReportFormat::Html),
+            _ => Err(format!("unknown report format `{s}`, expected `html`")),
+        }
+    }
+}
+
+/// Benchmarks the selected days. Every run is added to the timing history of the year, with `compare`
+/// the run is compared to the latest previous timings, optionally at a revision.
+pub fn handle(
+    year: Year,
+    days: Option<DaySet>,
+    mode: Mode,
+    run_all: bool,
+    store: bool,
+    compare: Option<Option<String>>,
+    report: Option<ReportFormat>,
+) {
+    let stored_timings = super::read_timings_or_exit(year);
+    let mut history = TimingHistory::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read timing history: {e}");
+        process::exit(1);
+    });
+
+    let days_to_run = days.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            if run_all || compare.is_some() {
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |days| days.days(year).into_iter().collect(),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    // NOTE: benchmarks always run serially so that days do not compete for resources.
+    let timings = run_multi(year, &days_to_run, mode, true, 1, None)
+        .timings
+        .unwrap();
+
+    if let Some(revision) = &compare {
+        // NOTE: branches and tags are resolved, anything else is matched as a commit prefix.
+        let revision = revision
+            .as_deref()
+            .map(|rev| git::resolve(rev).unwrap_or_else(|| rev.to_string()));
+
+        let baseline = history.baseline(revision.as_deref());
+        println!();
+        if baseline.data.is_empty() {
+            match revision {
+                Some(revision) => println!(
+                    "No runs at revision {revision} in the timing history, nothing to compare with."
+                ),
+                None => {
+                    println!("No previous runs in the timing history, nothing to compare with.")
+                }
+            }
+        } else {
+            match revision {
+                Some(revision) => {
+                    let short = &revision[..revision.len().min(7)];
+                    println!("{ANSI_BOLD}Comparison with {short}{ANSI_RESET}");
+                }
+                None => println!("{ANSI_BOLD}Comparison with previous runs{ANSI_RESET}"),
+            }
+            print_table(format_comparison(
+                &changes(&baseline, &timings),
+                NOISE_PERCENT,
+            ));
+        }
+    }
+
+    // NOTE: nothing was benched if every selected day is benched already or not solved.
+    if !timings.data.is_empty() {
+        history.record(timings.clone());
+        if history.store_file(year).is_err() {
+            eprintln!("Failed to store timing history.");
+        }
+    }
+
+    if report == Some(ReportFormat::Html) {
+        println!();
+        match report::write_html(year, &stored_timings, &timings, &history) {
+            Ok(path) => println!("Wrote benchmark report to {}.", path.display()),
+            Err(e) => eprintln!("Failed to write benchmark report: {e}"),
+        }
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
+
+        if let Err(e) = readme_stars::update(year) {
+            eprintln!("Failed to update stars in README: {e}");
+        }
+    }
+}
+
+/// Benches the selected days, or all days with stored benchmarks, and exits with an error if any part
+/// is slower than its stored benchmark by more than `threshold` percent, fails, or is no longer benched.
+/// Every day is benched several times and only the fastest run counts, so noise does not fail the check.
+pub fn check(year: Year, days: Option<DaySet>, mode: Mode, threshold: f64) {
+    let stored_timings = super::read_timings_or_exit(year);
+
+    let days_to_run: HashSet<Day> = days.map_or_else(
+        || stored_timings.data.iter().map(|t| t.day).collect(),
+        |days| days.days(year).into_iter().collect(),
+    );
+
+    if days_to_run.is_empty() {
+        println!("No stored benchmarks to check against, run `cargo time --store` first.");
+        return;
+    }
+
+    let answers = Answers::read_from_file(year);
+    let mut outcomes: Vec<DayOutcome> = vec![];
+
+    let runs: Vec<Timings> = (0..CHECK_RUNS)
+        .map(|_| {
+            let result = run_multi(year, &days_to_run, mode, true, 1, None);
+            let mut days = result.days;
+            summary::check_answers(&mut days, &answers);
+            outcomes.extend(days);
+            result.timings.unwrap()
+        })
+        .collect();
+
+    let timings = fastest(&runs);
+    let failures = failures(&stored_timings, &outcomes, &timings);
+    let changes = changes(&stored_timings, &timings);
+    let regressions = changes
+        .iter()
+        .filter(|change| change.percent.is_some_and(|percent| percent > threshold))
+        .count();
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Check against stored benchmarks (fastest of {CHECK_RUNS} runs){ANSI_RESET}"
+    );
+    print_table(format_comparison(&changes, threshold));
+    println!();
+
+    for (day, part, reason) in &failures {
+        eprintln!("Day {day} part {part}: {reason}.");
+    }
+
+    if regressions > 0 {
+        eprintln!("{regressions} part(s) are slower than their stored benchmark by more than {threshold}%.");
+    }
+
+    if regressions > 0 || !failures.is_empty() {
+        process::exit(1);
+    }
+
+    println!("No part is slower than its stored benchmark by more than {threshold}%.");
+}
+
+/// The change of a benched part compared to a baseline.
+struct Change {
+    day: Day,
+    part: u8,
+    before: Option<f64>,
+    after: f64,
+    /// The change in percent, if the part has been benched before.
+    percent: Option<f64>,
+}
+
+/// Returns the changes of every benched part of `current`.
+fn changes(baseline: &Timings, current: &Timings) -> Vec<Change> {
+    current
+        .data
+        .iter()
+        .flat_map(|timing| [1, 2].map(|part| (timing, part)))
+        .filter_map(|(timing, part)| {
+            let after = timing.part_nanos(part)?;
+            let before = baseline.get(timing.day).and_then(|t| t.part_nanos(part));
+
+            Some(Change {
+                day: timing.day,
+                part,
+                before,
+                after,
+                percent: before.map(|before| (after - before) / before * 100.0),
+            })
+        })
+        .collect()
+}
+
+/// Returns the parts that failed in any run, and the parts with a stored benchmark that were not benched.
+/// Parts without a stored benchmark that are not solved yet are not failures.
+fn failures(
+    stored: &Timings,
+    outcomes: &[DayOutcome],
+    current: &Timings,
+) -> Vec<(Day, u8, String)> {
+    let is_stored = |day: Day, part: u8| stored.get(day).and_then(|t| t.part_nanos(part)).is_some();
+
+    let mut failures: Vec<(Day, u8, String)> = vec![];
+    let mut add = |day: Day, part: u8, reason: String| {
+        if !failures.iter().any(|(d, p, _)| *d == day && *p == part) {
+            failures.push((day, part, reason));
+        }
+    };
+
+    for outcome in outcomes {
+        for part in &outcome.parts {
+            if part.status.is_failure()
+                && (part.status != Status::Unsolved || is_stored(outcome.day, part.part))
+            {
+                add(outcome.day, part.part, part.status.to_string());
+            }
+        }
+    }
+
+    for timing in &stored.data {
+        let is_checked = outcomes.iter().any(|o| o.day == timing.day);
+        for part in [1, 2] {
+            let is_benched = current
+                .get(timing.day)
+                .and_then(|t| t.part_nanos(part))
+                .is_some();
+            if is_checked && timing.part_nanos(part).is_some() && !is_benched {
+                add(timing.day, part, "not benched".into());
+            }
+        }
+    }
+
+    failures.sort_unstable_by_key(|(day, part, _)| (*day, *part));
+    failures
+}
+
+/// Formats changes as a table, highlighting parts that got slower (red) or faster (green) by more than `highlight_percent`.
+fn format_comparison(changes: &[Change], highlight_percent: f64) -> Vec<String> {
+    let header = ["Day", "Part", "Before", "After", "Change"].map(String::from);
+
+    let rows: Vec<[String; 5]> = changes
+        .iter()
+        .map(|change| {
+            let percent = change.percent.map_or_else(
+                || "new".into(),
+                |percent| {
+                    if percent > highlight_percent {
+                        format!("{ANSI_RED}{percent:+.1}%{ANSI_RESET}")
+                    } else if percent < -highlight_percent {
+                        format!("{ANSI_GREEN}{percent:+.1}%{ANSI_RESET}")
+                    } else {
+                        format!("{percent:+.1}%")
+                    }
+                },
+            );
+
+            [
+                change.day.to_string(),
+                change.part.to_string(),
+                change.before.map_or_else(|| "-".into(), format_nanos),
+                format_nanos(change.after),
+                percent,
+            ]
+        })
+        .collect();
+
+    format_table(&header, &rows)
+}
+
+/// Keeps the fastest timing of every part over several runs.
+fn fastest(runs: &[Timings]) -> Timings {
+    let mut fastest = Timings::default();
+
+    for timing in runs.iter().flat_map(|run| &run.data) {
+        let Some(index) = fastest.data.iter().position(|t| t.day == timing.day) else {
+            fastest.data.push(timing.clone());
+            continue;
+        };
+
+        let best = &mut fastest.data[index];
+        for part in [1, 2] {
+            let is_faster = match (timing.part_nanos(part), best.part_nanos(part)) {
+                (Some(nanos), Some(best_nanos)) => nanos < best_nanos,
+                (Some(_), None) => true,
+                _ => false,
+            };
+
+            if is_faster {
+                best.set_part(part, timing.part(part).copied());
+            }
+        }
+    }
+
+    fastest.data.sort_unstable_by_key(|t| t.day);
+    fastest
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{changes, failures, fastest, format_comparison, NOISE_PERCENT};
+    use crate::{
+        day,
+        template::{
+            summary::{DayOutcome, PartOutcome, Status},
+            timings::{PartTiming, Timing, Timings},
+            ANSI_GREEN, ANSI_RED, ANSI_RESET,
+        },
+    };
+
+    fn part(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming::new(millis * 1_000_000_f64, None))
+    }
+
+    fn timings(day_1: (f64, f64), day_2: Option<f64>) -> Timings {
+        let mut data = vec![Timing {
+            day: day!(1),
+            part_1: part(day_1.0),
+            part_2: part(day_1.1),
+            total_nanos: 0_f64,
+        }];
+
+        if let Some(part_1) = day_2 {
+            data.push(Timing {
+                day: day!(2),
+                part_1: part(part_1),
+                part_2: None,
+                total_nanos: 0_f64,
+            });
+        }
+
+        Timings { data }
+    }
+
+    #[test]
+    fn compares_timings() {
+        let baseline = timings((10.0, 2.0), None);
+        let current = timings((12.0, 1.0), Some(0.005));
+
+        assert_eq!(
+            format_comparison(&changes(&baseline, &current), NOISE_PERCENT),
+            [
+                "Day  Part  Before  After   Change".to_string(),
+                format!("01   1     10.0ms  12.0ms  {ANSI_RED}+20.0%{ANSI_RESET}"),
+                format!("01   2     2.0ms   1.0ms   {ANSI_GREEN}-50.0%{ANSI_RESET}"),
+                "02   1     -       5.0µs   new".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_fastest_timings() {
+        let runs = [timings((10.0, 2.0), None), timings((9.0, 3.0), Some(0.005))];
+
+        let fastest = fastest(&runs);
+        let day_1 = fastest.get(day!(1)).unwrap();
+        assert_eq!(day_1.part_1, part(9.0));
+        assert_eq!(day_1.part_2, part(2.0));
+        assert_eq!(day_1.total_nanos, 11_000_000_f64);
+        assert!(fastest.get(day!(2)).is_some());
+    }
+    #[test]
+    fn reports_failed_and_missing_parts() {
+        let stored = timings((10.0, 2.0), Some(1.0));
+        let current = timings((10.0, 2.0), None);
+
+        let outcome = |day, part_2| DayOutcome {
+            day,
+            parts: vec![
+                PartOutcome::from_answer(1, Some("1".into()), Duration::ZERO),
+                PartOutcome::failed(2, part_2),
+            ],
+        };
+
+        // NOTE: day 2 only has a stored benchmark for part 1, so its unsolved part 2 is not a failure.
+        let outcomes = [
+            outcome(day!(1), Status::Panicked),
+            outcome(day!(2), Status::Unsolved),
+        ];
+
+        assert_eq!(
+            failures(&stored, &outcomes, &current),
+            [
+                (day!(1), 2, "panicked".to_string()),
+                (day!(2), 1, "not benched".to_string()),
+            ]
+        );
+        assert_eq!(failures(&stored, &outcomes[..0], &current), []);
     }
 }
@@ -2,13 +2,19 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
 #[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+pub(super) const SERVER_UTC_OFFSET: i32 = -5;
+
+/// The last day of the longest events. See [`Year::day_count`] for the number of days of a specific event.
+pub const MAX_DAY: u8 = 25;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Events that run for fewer days only accept the days up to their [`Year::day_count`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -25,12 +31,22 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > MAX_DAY {
             return None;
         }
         Some(Self(day))
     }
 
+    /// Parses a day that is part of the event of the provided year.
+    pub fn parse_in(s: &str, year: Year) -> Result<Self, DayFromStrError> {
+        let err = DayFromStrError {
+            max: year.day_count(),
+        };
+
+        let day = s.parse().map_err(|_| err)?;
+        Self::new(day).filter(|day| year.contains(*day)).ok_or(err)
+    }
+
     // Not part of the public API
     #[doc(hidden)]
     pub const fn __new_unchecked(day: u8) -> Self {
@@ -38,7 +54,7 @@ impl Day {
     }
 
     /// Converts the [`Day`] into an [`u8`].
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
 }
@@ -46,10 +62,11 @@ impl Day {
 #[cfg(feature = "today")]
 impl Day {
     /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Use [`PuzzleId::today`](crate::template::PuzzleId::today) to respect the length of the current event.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        if today.month() == 12 && today.day() <= u32::from(MAX_DAY) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -81,39 +98,45 @@ impl FromStr for Day {
     type Err = DayFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let err = DayFromStrError { max: MAX_DAY };
+        let day = s.parse().map_err(|_| err)?;
+        Self::new(day).ok_or(err)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    max: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", self.max)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of advent of a year, from the 1st to the last day of the event.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of a year, from the 1st to the last day of the event.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.day_count(),
+        }
     }
 }
 
@@ -121,10 +144,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and the day count of an event is never above 25.
         let day = Day(self.current);
         self.current += 1;
 
@@ -155,10 +178,11 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{all_days, Day};
+    use crate::template::Year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(Year::new(2024).unwrap());
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -187,6 +211,21 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_respects_day_count() {
+        let days: Vec<Day> = all_days(Year::new(2025).unwrap()).collect();
+        assert_eq!(days.len(), 12);
+        assert_eq!(days.last(), Some(&Day(12)));
+    }
+
+    #[test]
+    fn parses_days_of_event() {
+        let year = Year::new(2025).unwrap();
+        assert_eq!(Day::parse_in("12", year).unwrap(), Day(12));
+        assert!(Day::parse_in("13", year).is_err());
+        assert!(Day::parse_in("13", Year::new(2024).unwrap()).is_ok());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,278 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::template::answers::Answers;
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, Year, MAX_DAY};
+
+/// A selection of days, parsed from a comma-separated list of selectors.
+///
+/// Every selector is either a day (`3`), an inclusive range of days (`3..7`, `3..=7`, `9-12`, `20..`),
+/// or one of the keywords `all`, `odd`, `even` and `unsolved`.
+///
+/// ```
+/// # use advent_of_code::template::{DaySet, Year};
+/// let days: DaySet = "1,5,9-12".parse().unwrap();
+/// assert_eq!(days.resolve(Year::new(2024).unwrap(), |_| false).len(), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet {
+    selectors: Vec<Selector>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector {
+    Range(RangeInclusive<u8>),
+    Odd,
+    Even,
+    Unsolved,
+}
+
+impl DaySet {
+    /// Parses a selection of days that are part of the event of the provided year.
+    pub fn parse_in(s: &str, year: Year) -> Result<Self, DaySetFromStrError> {
+        parse_selectors(s, year.day_count())
+    }
+
+    /// A set containing every day of advent. Resolves to the days of the event of a year.
+    pub fn all() -> Self {
+        Self {
+            selectors: vec![Selector::Range(1..=MAX_DAY)],
+        }
+    }
+
+    /// Returns the selected days of a year. Days are considered solved if both parts have
+    /// a stored benchmark in the year's `timings.json` or a known answer in its `answers.json`.
+    pub fn days(&self, year: Year) -> BTreeSet<Day> {
+        if !self.selectors.contains(&Selector::Unsolved) {
+            return self.resolve(year, |_| false);
+        }
+
+        let timings = Timings::read_from_file(year).unwrap_or_else(|e| {
+            eprintln!("Ignoring stored timings: {e}");
+            Timings::default()
+        });
+        let answers = Answers::read_from_file(year);
+
+        self.resolve(year, |day| {
+            timings.is_day_complete(day)
+                || (answers.get(day, 1).is_some() && answers.get(day, 2).is_some())
+        })
+    }
+
+    /// Returns the selected days that are part of the year's event, using `is_solved` to evaluate
+    /// the `unsolved` keyword.
+    pub fn resolve(&self, year: Year, is_solved: impl Fn(Day) -> bool) -> BTreeSet<Day> {
+        all_days(year)
+            .filter(|day| {
+                self.selectors.iter().any(|selector| match selector {
+                    Selector::Range(range) => range.contains(&day.into_inner()),
+                    Selector::Odd => day.into_inner() % 2 == 1,
+                    Selector::Even => day.into_inner() % 2 == 0,
+                    Selector::Unsolved => !is_solved(*day),
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<Day> for DaySet {
+    fn from(day: Day) -> Self {
+        Self {
+            selectors: vec![Selector::Range(day.into_inner()..=day.into_inner())],
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_selectors(s, MAX_DAY)
+    }
+}
+
+/// Parses a comma-separated list of selectors, rejecting days after `max`.
+fn parse_selectors(s: &str, max: u8) -> Result<DaySet, DaySetFromStrError> {
+    let selectors = s
+        .split(',')
+        .map(str::trim)
+        .map(|selector| parse_selector(selector, max))
+        .collect::<Result<_, _>>()?;
+
+    Ok(DaySet { selectors })
+}
+
+fn parse_selector(s: &str, max: u8) -> Result<Selector, DaySetFromStrError> {
+    let err = || DaySetFromStrError {
+        selection: s.to_string(),
+        max,
+    };
+
+    let parse_bound = |bound: &str, default: u8| -> Result<u8, DaySetFromStrError> {
+        if bound.is_empty() {
+            return Ok(default);
+        }
+        Day::from_str(bound)
+            .map(Day::into_inner)
+            .ok()
+            .filter(|day| *day <= max)
+            .ok_or_else(err)
+    };
+
+    match s.to_lowercase().as_str() {
+        "all" => return Ok(Selector::Range(1..=MAX_DAY)),
+        "odd" => return Ok(Selector::Odd),
+        "even" => return Ok(Selector::Even),
+        "unsolved" => return Ok(Selector::Unsolved),
+        _ => {}
+    }
+
+    // NOTE: `3..7` includes day 7 like `3-7`, `3..=7` is accepted for those used to rust ranges.
+    let range = if let Some((start, end)) = s
+        .split_once("..=")
+        .or_else(|| s.split_once(".."))
+        .or_else(|| s.split_once('-'))
+    {
+        parse_bound(start, 1)?..=parse_bound(end, MAX_DAY)?
+    } else if !s.is_empty() {
+        let day = parse_bound(s, 0)?;
+        day..=day
+    } else {
+        return Err(err());
+    };
+
+    if range.is_empty() {
+        return Err(err());
+    }
+
+    Ok(Selector::Range(range))
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError {
+    selection: String,
+    max: u8,
+}
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day selection `{}`, expecting days between 1 and {} (e.g. `3`, `3..7`, `1,5,9-12`, `odd` or `unsolved`)",
+            self.selection, self.max
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DaySet;
+    use crate::{day, template::Year};
+
+    fn parse_in(s: &str, year: u16) -> Vec<u8> {
+        let year = Year::new(year).unwrap();
+        DaySet::parse_in(s, year)
+            .unwrap()
+            .resolve(year, |day| day.into_inner() < 10)
+            .into_iter()
+            .map(|day| day.into_inner())
+            .collect()
+    }
+
+    fn parse(s: &str) -> Vec<u8> {
+        parse_in(s, 2024)
+    }
+
+    #[test]
+    fn parses_single_days() {
+        assert_eq!(parse("3"), [3]);
+        assert_eq!(parse("03"), [3]);
+    }
+
+    #[test]
+    fn parses_ranges() {
+        assert_eq!(parse("3..7"), [3, 4, 5, 6, 7]);
+        assert_eq!(parse("3..3"), [3]);
+        assert_eq!(parse("..2"), [1, 2]);
+        assert_eq!(parse("23.."), [23, 24, 25]);
+    }
+
+    #[test]
+    fn parses_inclusive_ranges() {
+        assert_eq!(parse("3..=5"), [3, 4, 5]);
+        assert_eq!(parse("..=2"), [1, 2]);
+        assert_eq!(parse("24..="), [24, 25]);
+        assert_eq!(parse("6..9"), parse("6..=9"));
+        assert_eq!(parse("6..9"), parse("6-9"));
+    }
+
+    #[test]
+    fn parses_dash_ranges() {
+        assert_eq!(parse("9-12"), [9, 10, 11, 12]);
+        assert_eq!(parse("-2"), [1, 2]);
+        assert_eq!(parse("24-"), [24, 25]);
+    }
+
+    #[test]
+    fn parses_lists() {
+        assert_eq!(parse("1,5,9-12"), [1, 5, 9, 10, 11, 12]);
+        assert_eq!(parse("5, 1, 5"), [1, 5]);
+    }
+
+    #[test]
+    fn parses_keywords() {
+        assert_eq!(parse("odd").len(), 13);
+        assert_eq!(parse("even").len(), 12);
+        assert_eq!(parse("all").len(), 25);
+        assert_eq!(parse("unsolved"), (10..=25).collect::<Vec<_>>());
+        assert_eq!(parse("odd,unsolved").len(), 21);
+    }
+
+    #[test]
+    fn converts_from_day() {
+        let year = Year::new(2024).unwrap();
+        assert_eq!(DaySet::from(day!(4)).resolve(year, |_| true).len(), 1);
+    }
+
+    #[test]
+    fn respects_day_count() {
+        assert_eq!(parse_in("all", 2025).len(), 12);
+        assert_eq!(parse_in("10..", 2025), [10, 11, 12]);
+        assert_eq!(
+            DaySet::all()
+                .resolve(Year::new(2025).unwrap(), |_| true)
+                .len(),
+            12
+        );
+    }
+
+    #[test]
+    fn rejects_days_outside_of_event() {
+        let year = Year::new(2025).unwrap();
+        for s in ["20", "10..13", "1,13", "13.."] {
+            let err = DaySet::parse_in(s, year).unwrap_err();
+            assert!(err.to_string().contains("between 1 and 12"), "{s}");
+        }
+        assert!(DaySet::parse_in("20", Year::new(2024).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_selections() {
+        for s in [
+            "", "0", "26", "7..3", "7-3", "1,,2", "1..30", "1..26", "weekdays",
+        ] {
+            assert!(s.parse::<DaySet>().is_err(), "{s}");
+        }
+    }
+}
@@ -0,0 +1,294 @@
+//! Module that extracts example tests from the source of a solution, and updates them.
+//! Only tests that follow the structure of the scaffolded template are recognized.
+use crate::template::templates::format_expected;
+
+/// An example test of a solution, e.g. `test_part_one`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleTest {
+    pub name: String,
+    pub part: u8,
+    /// The part suffix of the example file, e.g. `Some(2)` for `03-2.txt`.
+    pub file_part: Option<u8>,
+    /// The expected answer, or [`None`] if the test expects no answer.
+    pub expected: Option<String>,
+}
+
+/// Extracts all example tests from the source of a solution.
+pub fn parse_tests(source: &str) -> Vec<ExampleTest> {
+    let Some((_, tests)) = source.split_once("#[cfg(test)]") else {
+        return vec![];
+    };
+
+    tests
+        .split("fn ")
+        .skip(1)
+        .filter_map(|source| {
+            // NOTE: formatting may break calls across lines, so whitespace is ignored for matching.
+            let block: String = source.chars().filter(|c| !c.is_whitespace()).collect();
+
+            let name = block.split('(').next()?.to_string();
+
+            let part = if block.contains("part_one(") {
+                1
+            } else if block.contains("part_two(") {
+                2
+            } else {
+                return None;
+            };
+
+            let file_part = if block.contains(r#"read_file("examples",DAY)"#) {
+                None
+            } else {
+                let (_, rest) = block.split_once(r#"read_file_part("examples",DAY,"#)?;
+                Some(rest.split([',', ')']).next()?.parse().ok()?)
+            };
+
+            let (_, assertion) = source.split_once("assert_eq!(")?;
+            let assertion = assertion.trim_start().strip_prefix("result")?;
+            let assertion = assertion.trim_start().strip_prefix(',')?;
+            let expected = parse_expected(take_balanced(assertion)?)?;
+
+            Some(ExampleTest {
+                name,
+                part,
+                file_part,
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// Counts the tests in the test module of a solution, including tests [`parse_tests`] does not recognize.
+pub fn count_tests(source: &str) -> usize {
+    source
+        .split_once("#[cfg(test)]")
+        .map_or(0, |(_, tests)| tests.matches("#[test]").count())
+}
+
+/// Returns the content up to the parenthesis that closes the macro call.
+fn take_balanced(s: &str) -> Option<&str> {
+    Some(
+        s[..balanced_end(s)?]
+            .trim()
+            .trim_end_matches(',')
+            .trim_end(),
+    )
+}
+
+/// Returns the index of the parenthesis that closes the macro call.
+fn balanced_end(s: &str) -> Option<usize> {
+    let mut depth = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Updates the example tests of a solution: sets the expected answer of each part if one is provided,
+/// and switches the tests to per-part example files (e.g. `03-1.txt`) if the parts use different examples.
+/// Only tests that read the main example file of their part are updated.
+pub fn update_tests(source: &str, answers: &[Option<String>; 2], is_split: bool) -> String {
+    let Some(start) = source.find("#[cfg(test)]") else {
+        return source.to_string();
+    };
+
+    let (head, tests) = source.split_at(start);
+
+    let blocks: Vec<String> = tests
+        .split("fn ")
+        .enumerate()
+        .map(|(i, block)| {
+            if i == 0 {
+                return block.to_string();
+            }
+            update_test(block, answers, is_split).unwrap_or_else(|| block.to_string())
+        })
+        .collect();
+
+    format!("{head}{}", blocks.join("fn "))
+}
+
+fn update_test(block: &str, answers: &[Option<String>; 2], is_split: bool) -> Option<String> {
+    let compact: String = block.chars().filter(|c| !c.is_whitespace()).collect();
+
+    let part: u8 = if compact.contains("part_one(") {
+        1
+    } else if compact.contains("part_two(") {
+        2
+    } else {
+        return None;
+    };
+
+    let reads_main_file = compact.contains(r#"read_file("examples",DAY)"#);
+    if !reads_main_file && !compact.contains(&format!(r#"read_file_part("examples",DAY,{part})"#)) {
+        return None;
+    }
+
+    let mut block = block.to_string();
+
+    if is_split && reads_main_file {
+        block = block.replacen(
+            r#"read_file("examples", DAY)"#,
+            &format!(r#"read_file_part("examples", DAY, {part})"#),
+            1,
+        );
+    }
+
+    if let Some(answer) = &answers[usize::from(part) - 1] {
+        let (_, assertion) = block.split_once("assert_eq!(")?;
+        let after_result = assertion.trim_start().strip_prefix("result")?;
+        let after_comma = after_result.trim_start().strip_prefix(',')?;
+        let value = &after_comma[..balanced_end(after_comma)?];
+
+        // NOTE: keep the whitespace and trailing comma around the expected value.
+        let expected = value.trim().trim_end_matches(',').trim_end();
+        let start = block.len() - after_comma.len() + value.find(expected)?;
+        let end = start + expected.len();
+
+        block.replace_range(start..end, &format_expected(Some(answer)));
+    }
+
+    Some(block)
+}
+
+/// Parses an expected value like `Some(42)` or `None`. Returns [`None`] for values that cannot be interpreted.
+fn parse_expected(value: &str) -> Option<Option<String>> {
+    if value == "None" {
+        return Some(None);
+    }
+
+    let inner = value.strip_prefix("Some(")?.strip_suffix(')')?.trim();
+
+    let inner = inner
+        .strip_suffix(".to_string()")
+        .or_else(|| inner.strip_suffix(".into()"))
+        .unwrap_or(inner);
+
+    Some(Some(inner.trim_matches('"').to_string()))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{count_tests, parse_tests, update_tests, ExampleTest};
+    use crate::{
+        day,
+        template::{
+            templates::{self, Placeholders},
+            PuzzleId, Year,
+        },
+    };
+
+    #[test]
+    fn parses_scaffolded_tests() {
+        let puzzle = PuzzleId::new(Year::new(2024).unwrap(), day!(1));
+        let source = templates::render(
+            &templates::find(templates::DEFAULT_TEMPLATE).unwrap(),
+            &Placeholders::new(puzzle),
+        );
+        let tests = parse_tests(&source);
+        assert_eq!(tests.len(), 2);
+        assert_eq!(count_tests(&source), 2);
+        assert_eq!(tests[0].name, "test_part_one");
+        assert_eq!(tests[0].part, 1);
+        assert_eq!(tests[0].file_part, None);
+        assert_eq!(tests[0].expected, None);
+        assert_eq!(tests[1].part, 2);
+    }
+
+    #[test]
+    fn parses_multi_example_tests() {
+        let source = r#"
+fn part_one(input: &str) -> Option<u32> { None }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", DAY, 2,
+        ));
+        assert_eq!(result, Some(48));
+    }
+
+    #[test]
+    fn test_part_two_text() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(
+            result,
+            Some("a b".to_string()),
+        );
+    }
+}
+"#;
+
+        assert_eq!(
+            parse_tests(source),
+            [
+                ExampleTest {
+                    name: "test_part_two".into(),
+                    part: 2,
+                    file_part: Some(2),
+                    expected: Some("48".into()),
+                },
+                ExampleTest {
+                    name: "test_part_two_text".into(),
+                    part: 2,
+                    file_part: None,
+                    expected: Some("a b".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_unrecognized_tests() {
+        let source = r#"
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("1 2").len(), 2);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(part_one(&input), Some(3));
+    }
+}
+"#;
+
+        assert_eq!(parse_tests(source), []);
+        assert_eq!(count_tests(source), 2);
+        assert_eq!(count_tests("fn main() {}"), 0);
+    }
+
+    #[test]
+    fn updates_scaffolded_tests() {
+        let puzzle = PuzzleId::new(Year::new(2024).unwrap(), day!(1));
+        let source = templates::render(
+            &templates::find(templates::DEFAULT_TEMPLATE).unwrap(),
+            &Placeholders::new(puzzle),
+        );
+
+        let updated = update_tests(&source, &[Some("11".into()), Some("a,b".into())], true);
+        let tests = parse_tests(&updated);
+
+        assert_eq!(tests[0].file_part, Some(1));
+        assert_eq!(tests[0].expected, Some("11".into()));
+        assert_eq!(tests[1].file_part, Some(2));
+        assert_eq!(tests[1].expected, Some("a,b".into()));
+        assert_eq!(update_tests(&updated, &[None, None], false), updated);
+    }
+}
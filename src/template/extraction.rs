@@ -0,0 +1,269 @@
+//! Module that extracts example inputs and their answers from a downloaded puzzle description.
+//! The extraction relies on the conventions of the puzzle text and should be reviewed before it is used.
+
+/// The example of a single part, as far as it could be extracted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartExample {
+    pub part: u8,
+    pub input: Option<String>,
+    /// The emphasised answer of the example, e.g. `11`.
+    pub answer: Option<String>,
+}
+
+/// The examples of the unlocked parts of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Extraction {
+    pub parts: Vec<PartExample>,
+}
+
+impl Extraction {
+    /// Returns `true` if part two uses a different example than part one.
+    pub fn is_split(&self) -> bool {
+        match (self.input(1), self.input(2)) {
+            (Some(one), Some(two)) => one != two,
+            _ => false,
+        }
+    }
+
+    /// Returns the example files to write, identified by their part suffix (e.g. `Some(2)` for `03-2.txt`).
+    pub fn files(&self) -> Vec<(Option<u8>, &str)> {
+        if self.is_split() {
+            self.parts
+                .iter()
+                .filter_map(|p| Some((Some(p.part), p.input.as_deref()?)))
+                .collect()
+        } else {
+            self.input(1)
+                .map(|input| (None, input))
+                .into_iter()
+                .collect()
+        }
+    }
+
+    /// Returns the example answers of both parts.
+    pub fn answers(&self) -> [Option<String>; 2] {
+        [1, 2].map(|part| {
+            self.parts
+                .iter()
+                .find(|p| p.part == part)
+                .and_then(|p| p.answer.clone())
+        })
+    }
+
+    fn input(&self, part: u8) -> Option<&str> {
+        self.parts
+            .iter()
+            .find(|p| p.part == part)
+            .and_then(|p| p.input.as_deref())
+    }
+}
+
+/// Extracts the examples from a puzzle description in markdown.
+///
+/// The example of a part is the first code block that follows a paragraph mentioning an example, its answer is
+/// the last emphasised code span of the part. Part two only has its own example if it differs from part one's.
+pub fn extract(markdown: &str) -> Extraction {
+    let (part_one, part_two) = match markdown.find("--- Part Two ---") {
+        Some(index) => (&markdown[..index], Some(&markdown[index..])),
+        None => (markdown, None),
+    };
+
+    let mut parts = vec![PartExample {
+        part: 1,
+        input: find_example(part_one)
+            .or_else(|| code_blocks(part_one).into_iter().next().map(|b| b.1)),
+        answer: find_answer(part_one),
+    }];
+
+    if let Some(part_two) = part_two {
+        parts.push(PartExample {
+            part: 2,
+            input: find_example(part_two).or_else(|| parts[0].input.clone()),
+            answer: find_answer(part_two),
+        });
+    }
+
+    Extraction { parts }
+}
+
+/// Returns the first code block that is introduced as an example.
+fn find_example(section: &str) -> Option<String> {
+    code_blocks(section)
+        .into_iter()
+        .find(|(intro, _)| intro.to_lowercase().contains("example"))
+        .map(|(_, block)| block)
+}
+
+/// Returns the fenced code blocks of a section, together with the paragraph that precedes each block.
+fn code_blocks(section: &str) -> Vec<(String, String)> {
+    let mut blocks = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut previous_paragraph: Vec<&str> = vec![];
+    let mut block: Option<(String, Vec<&str>)> = None;
+
+    for line in section.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+
+        match (&mut block, is_fence) {
+            (None, true) => {
+                let intro = if paragraph.is_empty() {
+                    &previous_paragraph
+                } else {
+                    &paragraph
+                };
+                block = Some((intro.join(" "), vec![]));
+            }
+            (None, false) if line.trim().is_empty() => {
+                if !paragraph.is_empty() {
+                    previous_paragraph = std::mem::take(&mut paragraph);
+                }
+            }
+            (None, false) => paragraph.push(line),
+            (Some((_, lines)), false) => lines.push(line),
+            (Some((intro, lines)), true) => {
+                let content = lines.join("\n").trim_matches('\n').to_string();
+                if !content.is_empty() {
+                    blocks.push((std::mem::take(intro), content));
+                }
+                paragraph.clear();
+                previous_paragraph.clear();
+                block = None;
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Returns the last emphasised code span of a section, which is where the puzzle states the example's answer.
+fn find_answer(section: &str) -> Option<String> {
+    let mut answer = None;
+
+    for (open, close) in [("`*", "*`"), ("*`", "`*")] {
+        let mut rest = section;
+        let mut offset = 0;
+
+        while let Some(start) = rest.find(open) {
+            let after = &rest[start + open.len()..];
+            let Some(end) = after.find(close) else {
+                break;
+            };
+
+            let value = &after[..end];
+            let position = offset + start;
+
+            if !value.is_empty() && value.trim() == value && !value.contains('\n') {
+                match answer {
+                    Some((p, _)) if p > position => {}
+                    _ => answer = Some((position, value.to_string())),
+                }
+            }
+
+            let consumed = start + open.len() + end + close.len();
+            offset += consumed;
+            rest = &rest[consumed..];
+        }
+    }
+
+    answer.map(|(_, value)| value)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, PartExample};
+
+    const PUZZLE: &str = r#"\--- Day 1: Historian Hysteria ---
+----------
+
+Throughout the Chief's office, the historically significant locations are listed.
+
+For example:
+
+```
+3   4
+4   3
+2   5
+```
+
+Within each pair, figure out how far apart the two numbers are:
+
+```
+3 - 2 = 1
+```
+
+In the example above, this is `2 + 1`, a total distance of `*11*`!
+
+Your actual left and right lists contain many location IDs. *What is the total distance between your lists?*
+
+\--- Part Two ---
+----------
+
+Here are the same example lists again:
+
+```
+3   4
+4   3
+2   5
+```
+
+So, for these example lists, the similarity score at the end of this process is `*31*`.
+"#;
+
+    #[test]
+    fn extracts_shared_example() {
+        let extraction = extract(PUZZLE);
+
+        assert_eq!(
+            extraction.parts,
+            [
+                PartExample {
+                    part: 1,
+                    input: Some("3   4\n4   3\n2   5".into()),
+                    answer: Some("11".into()),
+                },
+                PartExample {
+                    part: 2,
+                    input: Some("3   4\n4   3\n2   5".into()),
+                    answer: Some("31".into()),
+                },
+            ]
+        );
+        assert!(!extraction.is_split());
+        assert_eq!(extraction.files(), [(None, "3   4\n4   3\n2   5")]);
+        assert_eq!(extraction.answers(), [Some("11".into()), Some("31".into())]);
+    }
+
+    #[test]
+    fn extracts_split_examples() {
+        let puzzle = PUZZLE.replace(
+            "Here are the same example lists again:\n\n```\n3   4",
+            "Here is a new example:\n\n```\n1   1",
+        );
+        let extraction = extract(&puzzle);
+
+        assert!(extraction.is_split());
+        assert_eq!(
+            extraction.files(),
+            [
+                (Some(1), "3   4\n4   3\n2   5"),
+                (Some(2), "1   1\n4   3\n2   5")
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_locked_part_two() {
+        let (part_one, _) = PUZZLE.split_once("\\--- Part Two").unwrap();
+        let extraction = extract(part_one);
+
+        assert_eq!(extraction.parts.len(), 1);
+        assert_eq!(extraction.answers(), [Some("11".into()), None]);
+    }
+
+    #[test]
+    fn extracts_alternative_emphasis() {
+        let extraction = extract("```\n1\n```\nthe answer is *`42`*.");
+        assert_eq!(extraction.answers(), [Some("42".into()), None]);
+        assert_eq!(extraction.files(), [(None, "1")]);
+    }
+}
@@ -0,0 +1,145 @@
+//! Module that reads revisions from the local `.git` directory, so the git binary is not required.
+//! Only annotated tags that are not packed need git, to peel them to their commit.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Returns the commit hash of `HEAD`, if the project is a git repository.
+pub fn head() -> Option<String> {
+    resolve("HEAD")
+}
+
+/// Resolves `HEAD`, a branch or a tag to its commit hash. Annotated tags are peeled to the commit they tag.
+pub fn resolve(name: &str) -> Option<String> {
+    let (git_dir, common_dir) = git_dirs(Path::new("."))?;
+    resolve_in(&git_dir, &common_dir, name, 0)
+}
+
+/// Returns the git directory of the worktree and the directory with the shared refs.
+fn git_dirs(root: &Path) -> Option<(PathBuf, PathBuf)> {
+    let dot_git = root.join(".git");
+
+    let git_dir = if dot_git.is_file() {
+        // NOTE: worktrees and submodules link to their git directory.
+        let link = fs::read_to_string(&dot_git).ok()?;
+        root.join(link.strip_prefix("gitdir:")?.trim())
+    } else if dot_git.is_dir() {
+        dot_git
+    } else {
+        return None;
+    };
+
+    let common_dir = fs::read_to_string(git_dir.join("commondir"))
+        .map(|dir| git_dir.join(dir.trim()))
+        .unwrap_or_else(|_| git_dir.clone());
+
+    Some((git_dir, common_dir))
+}
+
+fn resolve_in(git_dir: &Path, common_dir: &Path, name: &str, depth: u8) -> Option<String> {
+    // symbolic refs can point to each other, but not endlessly.
+    if depth > 5 {
+        return None;
+    }
+
+    let candidates = [
+        name.to_string(),
+        format!("refs/heads/{name}"),
+        format!("refs/tags/{name}"),
+    ];
+
+    for candidate in &candidates {
+        let contents = [git_dir, common_dir]
+            .iter()
+            .find_map(|dir| fs::read_to_string(dir.join(candidate)).ok());
+
+        if let Some(contents) = contents {
+            let contents = contents.trim();
+            return match contents.strip_prefix("ref:") {
+                Some(target) => resolve_in(git_dir, common_dir, target.trim(), depth + 1),
+                None if candidate.starts_with("refs/tags/") => peel_tag(common_dir, contents),
+                None => Some(contents.to_string()),
+            };
+        }
+    }
+
+    // NOTE: annotated tags are followed by a line with the peeled commit, e.g. `^abc123`.
+    let packed_refs = fs::read_to_string(common_dir.join("packed-refs")).ok()?;
+    let mut lines = packed_refs.lines().filter(|line| !line.starts_with('#'));
+
+    while let Some(line) = lines.next() {
+        let Some((hash, reference)) = line.split_once(' ') else {
+            continue;
+        };
+        if !candidates.iter().any(|c| c == reference) {
+            continue;
+        }
+
+        let peeled = lines.next().and_then(|line| line.strip_prefix('^'));
+        return Some(peeled.unwrap_or(hash).to_string());
+    }
+
+    None
+}
+
+/// Peels a tag that is stored as a loose ref. Annotated tags point to a compressed tag object,
+/// which is read with git. Without git, the tag is assumed to be a lightweight tag of a commit.
+fn peel_tag(common_dir: &Path, hash: &str) -> Option<String> {
+    let output = Command::new("git")
+        .arg("--git-dir")
+        .arg(common_dir)
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("{hash}^{{commit}}"))
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => {
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+        _ => Some(hash.to_string()),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{git_dirs, resolve_in};
+
+    #[test]
+    fn resolves_refs() {
+        let root = std::env::temp_dir().join(format!("aoc-git-{}", std::process::id()));
+        let git_dir = root.join(".git");
+        fs::create_dir_all(git_dir.join("refs/heads")).unwrap();
+        fs::write(git_dir.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        fs::write(git_dir.join("refs/heads/main"), "abc123\n").unwrap();
+        fs::write(
+            git_dir.join("packed-refs"),
+            "# pack-refs with: peeled\ndef456 refs/tags/v1\n^000000\nfed654 refs/tags/v2\n",
+        )
+        .unwrap();
+
+        let (git_dir, common_dir) = git_dirs(&root).unwrap();
+        assert_eq!(
+            resolve_in(&git_dir, &common_dir, "HEAD", 0),
+            Some("abc123".into())
+        );
+        assert_eq!(
+            resolve_in(&git_dir, &common_dir, "main", 0),
+            Some("abc123".into())
+        );
+        assert_eq!(
+            resolve_in(&git_dir, &common_dir, "v1", 0),
+            Some("000000".into())
+        );
+        assert_eq!(
+            resolve_in(&git_dir, &common_dir, "v2", 0),
+            Some("fed654".into())
+        );
+        assert_eq!(resolve_in(&git_dir, &common_dir, "unknown", 0), None);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,334 @@
+//! Module that summarises heap profiles written by dhat, see <https://docs.rs/dhat>.
+//! The profile lists allocation points with their stack frames, which are grouped by the first frame in the solution or library.
+use std::{collections::HashMap, fs, path::Path, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::timings::format_bytes;
+
+/// The allocations of a source location.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Site {
+    /// The file and line, e.g. `src/bin/2024/01.rs:14`, or `(other)` for allocations outside the solution.
+    pub location: String,
+    pub total_bytes: u64,
+    pub total_blocks: u64,
+    /// Bytes allocated at this site that were live when the heap size peaked.
+    pub peak_bytes: u64,
+}
+
+/// A summarised heap profile.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HeapProfile {
+    pub total_bytes: u64,
+    /// The number of allocations.
+    pub total_blocks: u64,
+    pub peak_bytes: u64,
+    pub peak_blocks: u64,
+    /// Allocation sites, largest first.
+    pub sites: Vec<Site>,
+}
+
+impl HeapProfile {
+    pub fn read_from_file(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        HeapProfile::try_from(contents)
+    }
+
+    /// Formats totals and the `top` largest allocation sites.
+    pub fn format_summary(&self, top: usize) -> Vec<String> {
+        let mut lines = vec![
+            format!(
+                "Total: {} in {} allocations",
+                format_bytes(self.total_bytes),
+                self.total_blocks
+            ),
+            format!(
+                "Peak:  {} in {} blocks",
+                format_bytes(self.peak_bytes),
+                self.peak_blocks
+            ),
+        ];
+
+        if !self.sites.is_empty() {
+            lines.push(String::new());
+            lines.push(format!(
+                "{:<32} {:>12} {:>8} {:>12}",
+                "Site", "Total", "Allocs", "At peak"
+            ));
+            for site in self.sites.iter().take(top) {
+                lines.push(format!(
+                    "{:<32} {:>12} {:>8} {:>12}",
+                    site.location,
+                    format_bytes(site.total_bytes),
+                    site.total_blocks,
+                    format_bytes(site.peak_bytes)
+                ));
+            }
+        }
+
+        lines
+    }
+
+    /// Formats the change of the totals and of the `top` allocation sites compared to `baseline`.
+    pub fn format_comparison(&self, baseline: &HeapProfile, top: usize) -> Vec<String> {
+        let row = |name: &str, before: u64, after: u64, bytes: bool| {
+            let format = |n: u64| {
+                if bytes {
+                    format_bytes(n)
+                } else {
+                    n.to_string()
+                }
+            };
+            format!(
+                "{name:<32} {:>12} {:>12} {:>8}",
+                format(before),
+                format(after),
+                format_change(before, after)
+            )
+        };
+
+        let mut lines = vec![
+            format!(
+                "{:<32} {:>12} {:>12} {:>8}",
+                "", "Before", "After", "Change"
+            ),
+            row("Total bytes", baseline.total_bytes, self.total_bytes, true),
+            row(
+                "Allocations",
+                baseline.total_blocks,
+                self.total_blocks,
+                false,
+            ),
+            row("Peak bytes", baseline.peak_bytes, self.peak_bytes, true),
+        ];
+
+        // NOTE: sites that disappeared are listed after the current sites.
+        let mut locations: Vec<&str> = self.sites.iter().map(|s| s.location.as_str()).collect();
+        for site in &baseline.sites {
+            if !locations.contains(&site.location.as_str()) {
+                locations.push(&site.location);
+            }
+        }
+
+        if !locations.is_empty() {
+            lines.push(String::new());
+            for location in locations.into_iter().take(top) {
+                let bytes = |profile: &HeapProfile| {
+                    profile
+                        .sites
+                        .iter()
+                        .find(|s| s.location == location)
+                        .map_or(0, |s| s.total_bytes)
+                };
+                lines.push(row(location, bytes(baseline), bytes(self), true));
+            }
+        }
+
+        lines
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_change(before: u64, after: u64) -> String {
+    if before == 0 {
+        return if after == 0 {
+            "0.0%".into()
+        } else {
+            "new".into()
+        };
+    }
+    format!(
+        "{:+.1}%",
+        (after as f64 - before as f64) / before as f64 * 100.0
+    )
+}
+
+/// Returns the location of a frame in the solution or library, e.g. `src/bin/2024/01.rs:14`.
+/// Frames are formatted as `0x1234: function (path/to/file.rs:14:22)`, where dhat shortens paths to
+/// their last three components. Files of dependencies can share those, e.g. `chumsky-0.9.3/src/lib.rs`,
+/// so library frames are matched by their function name.
+fn frame_location(frame: &str) -> Option<String> {
+    let (function, location) = frame.strip_suffix(')')?.rsplit_once(" (")?;
+    let function = function.split_once(": ").map_or(function, |(_, f)| f);
+
+    let (location, _column) = location.rsplit_once(':')?;
+    let (file, line) = location.rsplit_once(':')?;
+    let components: Vec<&str> = file.split('/').collect();
+
+    // solutions are stored as `bin/<year>/NN.rs`, or `bin/NN.rs` in older layouts.
+    let is_day = |name: &str| {
+        name.strip_suffix(".rs")
+            .is_some_and(|n| n.len() == 2 && n.bytes().all(|b| b.is_ascii_digit()))
+    };
+    let is_year = |name: &str| name.len() == 4 && name.bytes().all(|b| b.is_ascii_digit());
+
+    match components.as_slice() {
+        [.., "bin", year, day] if is_year(year) && is_day(day) => {
+            Some(format!("src/bin/{year}/{day}:{line}"))
+        }
+        [.., "bin", day] if is_day(day) => Some(format!("src/bin/{day}:{line}")),
+        [.., "src", "lib.rs"]
+            if function
+                .trim_start_matches('<')
+                .starts_with("advent_of_code::") =>
+        {
+            Some(format!("src/lib.rs:{line}"))
+        }
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for HeapProfile {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let frames: Vec<&str> = json
+            .get("ftbl")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("expected `ftbl` to be an array of frames.")?
+            .iter()
+            .map(|f| f.get::<String>().map_or("", String::as_str))
+            .collect();
+
+        let points = json
+            .get("pps")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("expected `pps` to be an array of allocation points.")?;
+
+        let mut profile = HeapProfile::default();
+        let mut sites: HashMap<String, Site> = HashMap::new();
+
+        for point in points {
+            let point = point
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected allocation points to be objects.")?;
+            let number = |key: &str| {
+                point
+                    .get(key)
+                    .and_then(|v| v.get::<f64>())
+                    .map_or(0, |n| *n as u64)
+            };
+
+            // NOTE: frames are listed innermost first, the first frame in the solution is the allocation site.
+            let location = point
+                .get("fs")
+                .and_then(|v| v.get::<Vec<JsonValue>>())
+                .into_iter()
+                .flatten()
+                .filter_map(|index| frames.get(*index.get::<f64>()? as usize))
+                .find_map(|frame| frame_location(frame))
+                .unwrap_or_else(|| "(other)".into());
+
+            let site = sites.entry(location.clone()).or_insert_with(|| Site {
+                location,
+                ..Site::default()
+            });
+            site.total_bytes += number("tb");
+            site.total_blocks += number("tbk");
+            site.peak_bytes += number("gb");
+
+            profile.total_bytes += number("tb");
+            profile.total_blocks += number("tbk");
+            profile.peak_bytes += number("gb");
+            profile.peak_blocks += number("gbk");
+        }
+
+        profile.sites = sites.into_values().collect();
+        profile.sites.sort_by(|a, b| {
+            b.total_bytes
+                .cmp(&a.total_bytes)
+                .then_with(|| a.location.cmp(&b.location))
+        });
+
+        Ok(profile)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{frame_location, HeapProfile};
+
+    fn profile(bytes: u64) -> HeapProfile {
+        HeapProfile::try_from(format!(
+            r#"{{
+                "dhatFileVersion": 2,
+                "mode": "rust-heap",
+                "pps": [
+                    {{ "tb": {bytes}, "tbk": 4, "gb": 512, "gbk": 1, "fs": [1, 2, 3] }},
+                    {{ "tb": 100, "tbk": 1, "gb": 0, "gbk": 0, "fs": [1, 4] }},
+                    {{ "tb": 24, "tbk": 2, "gb": 24, "gbk": 2, "fs": [1, 5] }}
+                ],
+                "ftbl": [
+                    "[root]",
+                    "0x1: alloc::raw_vec::finish_grow (src/raw_vec/mod.rs:558:24)",
+                    "0x2: chumsky::Parser::parse (chumsky-0.9.3/src/lib.rs:241:37)",
+                    "0x3: _2024_01::parse (bin/2024/01.rs:14:22)",
+                    "0x4: <advent_of_code::Grid as core::str::FromStr>::from_str (crate/src/lib.rs:8:9)",
+                    "0x5: std::io::stdio::stdout (src/io/stdio.rs:719:14)"
+                ]
+            }}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_frame_locations() {
+        assert_eq!(
+            frame_location("0x3: _2024_01::parse (bin/2024/01.rs:14:22)"),
+            Some("src/bin/2024/01.rs:14".into())
+        );
+        assert_eq!(
+            frame_location("0x3: day_01::parse (aoc/src/bin/01.rs:14:22)"),
+            Some("src/bin/01.rs:14".into())
+        );
+        assert_eq!(
+            frame_location("0x4: <advent_of_code::Grid as core::str::FromStr>::from_str (crate/src/lib.rs:8:9)"),
+            Some("src/lib.rs:8".into())
+        );
+        assert_eq!(
+            frame_location("0x2: chumsky::Parser::parse (chumsky-0.9.3/src/lib.rs:241:37)"),
+            None
+        );
+        assert_eq!(frame_location("[root]"), None);
+    }
+
+    #[test]
+    fn summarises_profile() {
+        let profile = profile(2048);
+
+        assert_eq!(profile.total_bytes, 2172);
+        assert_eq!(profile.total_blocks, 7);
+        assert_eq!(profile.peak_bytes, 536);
+        assert_eq!(profile.peak_blocks, 3);
+
+        let locations: Vec<&str> = profile.sites.iter().map(|s| s.location.as_str()).collect();
+        assert_eq!(
+            locations,
+            ["src/bin/2024/01.rs:14", "src/lib.rs:8", "(other)"]
+        );
+        assert_eq!(profile.sites[0].peak_bytes, 512);
+
+        let summary = profile.format_summary(1);
+        assert_eq!(summary[0], "Total: 2.1 KiB in 7 allocations");
+        assert_eq!(summary.len(), 5);
+    }
+
+    #[test]
+    fn compares_profiles() {
+        let comparison = profile(1024).format_comparison(&profile(2048), 10);
+
+        assert!(comparison[1].starts_with("Total bytes"));
+        assert!(comparison[1].ends_with("-47.1%"));
+        assert!(comparison[5].starts_with("src/bin/2024/01.rs:14"));
+        assert!(comparison[5].ends_with("-50.0%"));
+    }
+}
@@ -0,0 +1,276 @@
+//! Module that converts the HTML of a puzzle page to markdown.
+//! Only the elements used by puzzle descriptions are supported, everything outside of `<article>` is dropped.
+
+enum Token<'a> {
+    Open { name: String, attrs: &'a str },
+    Close(String),
+    Text(&'a str),
+}
+
+/// Converts the puzzle descriptions of a puzzle page to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut article_depth = 0;
+    let mut in_pre = false;
+    let mut links: Vec<Option<String>> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open { name, attrs } => {
+                if name == "article" {
+                    article_depth += 1;
+                    start_block(&mut out);
+                    continue;
+                }
+                if article_depth == 0 {
+                    continue;
+                }
+
+                match name.as_str() {
+                    "h2" => {
+                        start_block(&mut out);
+                        out.push_str("## ");
+                    }
+                    "p" | "ul" => start_block(&mut out),
+                    "pre" => {
+                        start_block(&mut out);
+                        out.push_str("```\n");
+                        in_pre = true;
+                    }
+                    "li" => {
+                        start_line(&mut out);
+                        out.push_str("- ");
+                    }
+                    "br" => out.push('\n'),
+                    "code" if !in_pre => out.push('`'),
+                    "em" if !in_pre => out.push('*'),
+                    "a" => {
+                        let href = attribute(attrs, "href");
+                        if href.is_some() {
+                            out.push('[');
+                        }
+                        links.push(href);
+                    }
+                    _ => {}
+                }
+            }
+            Token::Close(name) => {
+                if name == "article" {
+                    article_depth -= 1;
+                    continue;
+                }
+                if article_depth == 0 {
+                    continue;
+                }
+
+                match name.as_str() {
+                    "pre" => {
+                        while out.ends_with('\n') {
+                            out.pop();
+                        }
+                        out.push_str("\n```");
+                        in_pre = false;
+                        start_block(&mut out);
+                    }
+                    "h2" | "p" | "ul" => start_block(&mut out),
+                    "code" if !in_pre => out.push('`'),
+                    "em" if !in_pre => out.push('*'),
+                    "a" => {
+                        if let Some(Some(href)) = links.pop() {
+                            out.push_str(&format!("]({href})"));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Token::Text(text) if article_depth > 0 => {
+                let text = decode_entities(text);
+                if in_pre {
+                    out.push_str(&text);
+                } else if out.is_empty() || out.ends_with('\n') {
+                    // NOTE: whitespace between block elements is not part of the text.
+                    out.push_str(text.replace('\n', " ").trim_start());
+                } else {
+                    out.push_str(&text.replace('\n', " "));
+                }
+            }
+            Token::Text(_) => {}
+        }
+    }
+
+    let mut markdown = out.trim().to_string();
+    markdown.push('\n');
+    markdown
+}
+
+/// Ensures that the output continues on a new paragraph.
+fn start_block(out: &mut String) {
+    trim_line_end(out);
+    if out.is_empty() {
+        return;
+    }
+    while !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+/// Ensures that the output continues on a new line.
+fn start_line(out: &mut String) {
+    trim_line_end(out);
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn trim_line_end(out: &mut String) {
+    while out.ends_with(' ') {
+        out.pop();
+    }
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = rest[1..end].trim_end_matches('/');
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+            continue;
+        }
+
+        let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        let name = name.to_lowercase();
+
+        // NOTE: scripts and styles may contain `<`, skip them entirely.
+        if name == "script" || name == "style" {
+            let close = format!("</{name}");
+            rest = rest.find(&close).map_or("", |end| &rest[end..]);
+        }
+
+        tokens.push(Token::Open { name, attrs });
+    }
+
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+
+    tokens
+}
+
+fn attribute(attrs: &str, name: &str) -> Option<String> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = attrs[start..].find('"')?;
+    Some(decode_entities(&attrs[start..start + end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .map(|end| &rest[1..=end])
+            .and_then(|entity| Some((entity, decode_entity(entity)?)));
+
+        match entity {
+            Some((entity, c)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = entity.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::to_markdown;
+
+    #[test]
+    fn converts_puzzle_description() {
+        let html = r#"<!DOCTYPE html>
+<html><head><script>if (a < b) {}</script></head><body>
+<header><h1><a href="/">Advent of Code</a></h1></header>
+<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is always present, see <a href="/2024/about">about</a>.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<ul>
+<li>The first pair is <code>3 &lt; 4</code>.</li>
+<li>A total distance of <code><em>11</em></code>!</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>The answer is <em><code>31</code></em>&#33;</p></article>
+</main></body></html>"#;
+
+        assert_eq!(
+            to_markdown(html),
+            "## --- Day 1: Historian Hysteria ---
+
+The *Chief Historian* is always present, see [about](/2024/about).
+
+For example:
+
+```
+3   4
+4   3
+```
+
+- The first pair is `3 < 4`.
+- A total distance of `*11*`!
+
+## --- Part Two ---
+
+The answer is *`31`*!
+"
+        );
+    }
+}
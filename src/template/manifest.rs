@@ -0,0 +1,63 @@
+//! Module that registers solutions as binary targets in `Cargo.toml`.
+//! Cargo only discovers binaries placed directly in `src/bin`, so every solution in `src/bin/<year>` needs a `[[bin]]` entry.
+use std::{fs, io};
+
+use crate::template::PuzzleId;
+
+static MANIFEST_PATH: &str = "Cargo.toml";
+
+/// Adds a binary target for the puzzle to `Cargo.toml`.
+/// Returns `false` if the manifest already contains the target.
+pub fn add_bin(puzzle: PuzzleId) -> Result<bool, io::Error> {
+    let manifest = fs::read_to_string(MANIFEST_PATH)?;
+
+    match add_bin_target(&manifest, puzzle) {
+        Some(manifest) => {
+            fs::write(MANIFEST_PATH, manifest)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+fn add_bin_target(manifest: &str, puzzle: PuzzleId) -> Option<String> {
+    if manifest.contains(&format!("name = \"{puzzle}\"")) {
+        return None;
+    }
+
+    let mut manifest = manifest.trim_end().to_string();
+    manifest.push_str(&format!(
+        "\n\n[[bin]]\nname = \"{puzzle}\"\npath = \"{}\"\n",
+        puzzle.bin_path()
+    ));
+
+    Some(manifest)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::add_bin_target;
+    use crate::{
+        day,
+        template::{PuzzleId, Year},
+    };
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(Year::new(2024).unwrap(), day!(3))
+    }
+
+    #[test]
+    fn appends_bin_target() {
+        let manifest = add_bin_target("[package]\nname = \"advent_of_code\"\n", puzzle()).unwrap();
+        assert_eq!(
+            manifest,
+            "[package]\nname = \"advent_of_code\"\n\n[[bin]]\nname = \"2024-03\"\npath = \"src/bin/2024/03.rs\"\n"
+        );
+    }
+
+    #[test]
+    fn skips_existing_bin_target() {
+        let manifest = add_bin_target("[package]\n", puzzle()).unwrap();
+        assert_eq!(add_bin_target(&manifest, puzzle()), None);
+    }
+}
@@ -0,0 +1,160 @@
+//! Module that renders puzzle descriptions in markdown to the terminal.
+//! Supports the markdown written by `download` as well as the one written by aoc-cli.
+use crate::template::{ANSI_BOLD, ANSI_HIGHLIGHT, ANSI_ITALIC, ANSI_RESET};
+
+/// Renders a puzzle description with ANSI styles: headings are bold, emphasis is italic,
+/// emphasised code (i.e. answers) is highlighted and code blocks are boxed.
+pub fn render(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut lines = markdown.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") {
+            let mut block = vec![];
+            for line in lines.by_ref() {
+                if line.trim().starts_with("```") {
+                    break;
+                }
+                block.push(line);
+            }
+            out.push_str(&render_code_block(&block));
+        } else if let Some(heading) = trimmed.strip_prefix("## ") {
+            out.push_str(&format!(
+                "{ANSI_BOLD}{}{ANSI_RESET}\n",
+                render_inline(heading)
+            ));
+        } else if lines.peek().is_some_and(|next| is_underline(next)) {
+            // NOTE: aoc-cli writes headings as a line followed by dashes.
+            lines.next();
+            out.push_str(&format!(
+                "{ANSI_BOLD}{}{ANSI_RESET}\n",
+                render_inline(trimmed)
+            ));
+        } else if let Some(item) = trimmed.strip_prefix("- ") {
+            out.push_str(&format!("  • {}\n", render_inline(item)));
+        } else {
+            out.push_str(&render_inline(line));
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && line.chars().all(|c| c == '-')
+}
+
+fn render_code_block(lines: &[&str]) -> String {
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut out = format!("┌{}┐\n", "─".repeat(width + 2));
+    for line in lines {
+        let padding = " ".repeat(width - line.chars().count());
+        out.push_str(&format!("│ {line}{padding} │\n"));
+    }
+    out.push_str(&format!("└{}┘\n", "─".repeat(width + 2)));
+    out
+}
+
+fn render_inline(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        // emphasised code, e.g. `*42*` or *`42`*.
+        if let Some((answer, tail)) =
+            enclosed(rest, "`*", "*`").or_else(|| enclosed(rest, "*`", "`*"))
+        {
+            out.push_str(&format!("{ANSI_HIGHLIGHT}{answer}{ANSI_RESET}"));
+            rest = tail;
+        } else if let Some((code, tail)) = enclosed(rest, "`", "`") {
+            out.push_str(code);
+            rest = tail;
+        } else if let Some((emphasis, tail)) = enclosed(rest, "*", "*") {
+            out.push_str(&format!("{ANSI_ITALIC}{emphasis}{ANSI_RESET}"));
+            rest = tail;
+        } else if let Some((link, tail)) = link(rest) {
+            out.push_str(&render_inline(link));
+            rest = tail;
+        } else if let Some(escaped) = rest.strip_prefix('\\').and_then(|r| r.chars().next()) {
+            out.push(escaped);
+            rest = &rest[1 + escaped.len_utf8()..];
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    out
+}
+
+/// Returns the non-empty content between `open` and `close` at the start of `text`, and the text after it.
+fn enclosed<'a>(text: &'a str, open: &str, close: &str) -> Option<(&'a str, &'a str)> {
+    let rest = text.strip_prefix(open)?;
+    let end = rest.find(close)?;
+    if end == 0 {
+        return None;
+    }
+    Some((&rest[..end], &rest[end + close.len()..]))
+}
+
+/// Returns the text of a link at the start of `text`, and the text after it.
+fn link(text: &str) -> Option<(&str, &str)> {
+    let (label, rest) = enclosed(text, "[", "](")?;
+    let end = rest.find(')')?;
+    Some((label, &rest[end + 1..]))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::render;
+    use crate::template::{ANSI_BOLD, ANSI_HIGHLIGHT, ANSI_ITALIC, ANSI_RESET};
+
+    #[test]
+    fn renders_puzzle_description() {
+        let markdown = "## --- Day 1: Historian Hysteria ---
+
+The *Chief* is [here](/2024). For example:
+
+```
+3   4
+10  3
+```
+
+- The answer is `*11*`, not `a*b`.
+";
+
+        assert_eq!(
+            render(markdown),
+            format!(
+                "{ANSI_BOLD}--- Day 1: Historian Hysteria ---{ANSI_RESET}
+
+The {ANSI_ITALIC}Chief{ANSI_RESET} is here. For example:
+
+┌───────┐
+│ 3   4 │
+│ 10  3 │
+└───────┘
+
+  • The answer is {ANSI_HIGHLIGHT}11{ANSI_RESET}, not a*b.
+"
+            )
+        );
+    }
+
+    #[test]
+    fn renders_aoc_cli_headings() {
+        assert_eq!(
+            render("\\--- Part Two ---\n----------\n*`31`*"),
+            format!("{ANSI_BOLD}--- Part Two ---{ANSI_RESET}\n{ANSI_HIGHLIGHT}31{ANSI_RESET}\n")
+        );
+    }
+}
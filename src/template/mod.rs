@@ -1,44 +1,90 @@
-use std::{env, fs};
+use std::{env, fs, path::PathBuf};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod aoc_client;
 pub mod commands;
+pub mod registry;
+pub mod run_multi;
 pub mod runner;
+pub mod spans;
 
 pub use day::*;
+pub use day_set::*;
+pub use puzzle::*;
+pub use templates::DEFAULT_TEMPLATE;
+pub use year::*;
 
+mod answers;
+mod chart;
 mod day;
+mod day_set;
+mod examples;
+mod extraction;
+mod git;
+mod heap_profile;
+mod html;
+mod manifest;
+mod markdown;
+mod puzzle;
 mod readme_benchmarks;
-mod run_multi;
+mod readme_stars;
+mod report;
+mod submissions;
+mod summary;
+mod templates;
+mod timing_history;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_HIGHLIGHT: &str = "\x1b[1;33m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// The project configuration file, which configures e.g. the benchmark table and the calendar.
+pub(crate) const CONFIG_PATH: &str = "aoc.json";
+
+/// Helper function that returns the path of a puzzle's text file. E.g. like `data/2024/inputs/01.txt`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn get_file_path(folder: &str, puzzle: PuzzleId) -> PathBuf {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+    cwd.join(puzzle.data_path(folder, "txt"))
+}
+
+/// Helper function that reads a text file to a string.
+#[must_use]
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    let f = fs::read_to_string(get_file_path(folder, puzzle));
     f.expect("could not open input file")
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Helper function that returns the path of a puzzle's text file with a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn get_file_part_path(folder: &str, puzzle: PuzzleId, part: u8) -> PathBuf {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
+    cwd.join("data")
+        .join(puzzle.year.to_string())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
+        .join(format!("{}-{part}.txt", puzzle.day))
+}
+
+/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+#[must_use]
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
+    let f = fs::read_to_string(get_file_part_path(folder, puzzle, part));
     f.expect("could not open input file")
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The year is read from the location of the solution (`src/bin/<year>/<day>.rs`), so `DAY` identifies
+/// the puzzle by both its year and day.
+///
+/// Also creates the constant `SOLUTION`, which registers the solution with the main binary.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -52,17 +98,69 @@ macro_rules! solution {
     };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        /// The current puzzle.
+        const DAY: $crate::template::PuzzleId = {
+            const YEAR: $crate::template::Year =
+                $crate::template::Year::__from_source_path(file!(), option_env!("AOC_YEAR"));
+            const _ASSERT: () = assert!(
+                $day <= YEAR.__day_count(option_env!("AOC_CALENDAR")),
+                concat!(
+                    "day `",
+                    $day,
+                    "` is not part of the event of the solution's year, see `calendar` in `aoc.json`"
+                ),
+            );
+            $crate::template::PuzzleId::new(YEAR, $crate::day!($day))
+        };
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        // NOTE: with the `registry` feature, the solution is also a module of the main binary, which has its own.
+        #[cfg(not(any(feature = "dhat-heap", feature = "registry")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::alloc::CountingAlloc = $crate::template::alloc::CountingAlloc;
+
+        /// The registry entry of the current day.
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                puzzle: DAY,
+                parts: &[$(
+                    $crate::template::registry::Part {
+                        part: $part,
+                        run: |input, is_timed, out| {
+                            $crate::template::runner::execute_part(
+                                $func, input, $part, is_timed, out,
+                            )
+                        },
+                    },
+                )*],
+            };
+
         fn main() {
             use $crate::template::runner::*;
+            // NOTE: a single profile covers all parts, so it can be summarised after the run.
+            #[cfg(feature = "dhat-heap")]
+            let _profiler = dhat::Profiler::new_heap();
             let input = $crate::template::read_file("inputs", DAY);
             $( run_part($func, &input, DAY, $part); )*
         }
     };
 }
+
+/// Records the duration of an expression as a phase of the current part and returns its value.
+/// Phases can be nested and are printed as a tree below the result of the part, e.g.
+///
+/// ```ignore
+/// let grid = advent_of_code::timed!("parse", parse(input));
+/// ```
+///
+/// For sections that are not a single expression, keep the guard returned by `spans::Span::enter` instead.
+#[macro_export]
+macro_rules! timed {
+    ($label:expr, $expr:expr) => {{
+        let _span = $crate::template::spans::Span::enter($label);
+        $expr
+    }};
+}
@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+use crate::template::{Day, Year};
+
+/// Identifies a puzzle by its year and day.
+///
+/// # Display
+/// This value displays as the name of the puzzle's solution binary, e.g. `2024-08`.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2024).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2024-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Returns the path of the puzzle's solution, e.g. `src/bin/2024/08.rs`.
+    #[must_use]
+    pub fn bin_path(self) -> String {
+        format!("src/bin/{}/{}.rs", self.year, self.day)
+    }
+
+    /// Returns the path of a file in one of the puzzle's data folders, e.g. `data/2024/inputs/08.txt`.
+    #[must_use]
+    pub fn data_path(self, folder: &str, extension: &str) -> String {
+        format!("data/{}/{folder}/{}.{extension}", self.year, self.day)
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle of the current day if it's part of this year's event, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let year = Year::today()?;
+        let day = Day::today().filter(|day| year.contains(*day))?;
+        Some(Self::new(year, day))
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
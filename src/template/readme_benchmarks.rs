@@ -1,13 +1,21 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{collections::HashMap, fmt::Display, fs, io, str::FromStr};
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use tinyjson::JsonValue;
 
-static MARKER: &str = "<!--- benchmarking table --->";
+use crate::template::chart;
+use crate::template::timings::{format_bytes, format_nanos, Timing, Timings};
+use crate::template::{PuzzleId, Year, CONFIG_PATH};
+
+/// The name of the markers around the table of a year, e.g. `<!--- benchmarking table 2024 --->`.
+static MARKER_NAME: &str = "benchmarking table";
+
+/// Returns the path of the runtime chart of a year, which is embedded below its table.
+fn chart_path(year: Year) -> String {
+    format!(".assets/benchmarks-{year}.svg")
+}
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     Parser(String),
@@ -20,22 +28,301 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => f.write_str(e),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// A column of the benchmark table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Column {
+    Day,
+    Parse,
+    Part1,
+    Part2,
+    Total,
+    Memory,
+    Samples,
+    Lines,
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(Column::Day),
+            "parse" => Ok(Column::Parse),
+            "part_1" => Ok(Column::Part1),
+            "part_2" => Ok(Column::Part2),
+            "total" => Ok(Column::Total),
+            "memory" => Ok(Column::Memory),
+            "samples" => Ok(Column::Samples),
+            "lines" => Ok(Column::Lines),
+            _ => Err(format!("unknown column `{s}`, expected one of day, parse, part_1, part_2, total, memory, samples, lines.")),
+        }
+    }
+}
+
+impl Column {
+    fn title(self) -> &'static str {
+        match self {
+            Column::Day => "Day",
+            Column::Parse => "Parse",
+            Column::Part1 => "Part 1",
+            Column::Part2 => "Part 2",
+            Column::Total => "Total",
+            Column::Memory => "Memory",
+            Column::Samples => "Samples",
+            Column::Lines => "Lines",
+        }
+    }
+
+    fn cell(self, config: &TableConfig, year: Year, timing: &Timing) -> String {
+        let puzzle = PuzzleId::new(year, timing.day);
+        let parts = [&timing.part_1, &timing.part_2];
+
+        let value = match self {
+            Column::Day => return config.day_cell(puzzle),
+            Column::Parse => parts
+                .iter()
+                .find_map(|p| p.and_then(|p| p.parse_nanos))
+                .map(format_nanos),
+            Column::Part1 => timing.part_1.map(|p| p.formatted()),
+            Column::Part2 => timing.part_2.map(|p| p.formatted()),
+            Column::Total => Some(format_nanos(timing.total_nanos)),
+            Column::Memory => parts
+                .iter()
+                .filter_map(|p| p.and_then(|p| p.memory_bytes))
+                .max()
+                .map(format_bytes),
+            Column::Samples => {
+                let samples: Vec<String> = parts
+                    .iter()
+                    .map(|p| {
+                        p.and_then(|p| p.samples)
+                            .map_or("-".into(), |s| s.to_string())
+                    })
+                    .collect();
+                parts
+                    .iter()
+                    .any(|p| p.is_some_and(|p| p.samples.is_some()))
+                    .then(|| samples.join(" / "))
+            }
+            Column::Lines => count_lines(&puzzle.bin_path()).map(|l| l.to_string()),
+        };
+
+        value.map_or_else(|| "-".into(), |v| format!("`{v}`"))
+    }
+}
+
+/// Counts the non-empty lines of a solution, if it exists.
+fn count_lines(path: &str) -> Option<usize> {
+    let source = fs::read_to_string(path).ok()?;
+    Some(source.lines().filter(|l| !l.trim().is_empty()).count())
+}
+
+/// How the rows of the benchmark table are sorted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SortOrder {
+    Day,
+    Total,
+}
+
+/// Configures the benchmark table, read from the `benchmark_table` object in `aoc.json`.
+#[derive(Clone, Debug)]
+struct TableConfig {
+    columns: Vec<Column>,
+    sort: SortOrder,
+    descending: bool,
+    heading_level: usize,
+    link_source: bool,
+    link_puzzle: bool,
+    chart: bool,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            columns: vec![Column::Day, Column::Part1, Column::Part2],
+            sort: SortOrder::Day,
+            descending: false,
+            heading_level: 2,
+            link_source: true,
+            link_puzzle: false,
+            chart: true,
+        }
+    }
+}
+
+impl TableConfig {
+    /// Reads the configuration of the project. If not present, returns the default table.
+    fn read_from_file() -> Result<Self, Error> {
+        match fs::read_to_string(CONFIG_PATH) {
+            Ok(contents) => TableConfig::try_from(contents)
+                .map_err(|e| Error::Parser(format!("{CONFIG_PATH}: {e}"))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(TableConfig::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn day_cell(&self, puzzle: PuzzleId) -> String {
+        let day = format!("Day {}", puzzle.day.into_inner());
+        let mut cell = if self.link_source {
+            format!("[{day}](./{})", puzzle.bin_path())
+        } else {
+            day
+        };
+        if self.link_puzzle {
+            cell.push_str(&format!(
+                " ([puzzle](https://adventofcode.com/{}/day/{}))",
+                puzzle.year,
+                puzzle.day.into_inner()
+            ));
+        }
+        cell
+    }
+
+    fn sort(&self, data: &mut [Timing]) {
+        match self.sort {
+            SortOrder::Day => data.sort_by_key(|t| t.day),
+            SortOrder::Total => data.sort_by(|a, b| a.total_nanos.total_cmp(&b.total_nanos)),
+        }
+        if self.descending {
+            data.reverse();
+        }
+    }
+}
+
+impl TryFrom<String> for TableConfig {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        let mut config = TableConfig::default();
+
+        let Some(table) = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("benchmark_table")
+        else {
+            return Ok(config);
+        };
+
+        let table = table
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected `benchmark_table` to be an object.")?;
+
+        for (key, value) in table {
+            match key.as_str() {
+                "columns" => {
+                    config.columns = value
+                        .get::<Vec<JsonValue>>()
+                        .ok_or("expected `columns` to be an array.")?
+                        .iter()
+                        .map(|c| {
+                            c.get::<String>()
+                                .ok_or_else(|| "expected columns to be strings.".to_string())
+                                .and_then(|c| Column::from_str(c))
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                "sort" => {
+                    config.sort = match value.get::<String>().map(String::as_str) {
+                        Some("day") => SortOrder::Day,
+                        Some("total") => SortOrder::Total,
+                        _ => return Err("expected `sort` to be `day` or `total`.".into()),
+                    };
+                }
+                "descending" => config.descending = boolean(value, key)?,
+                "link_source" => config.link_source = boolean(value, key)?,
+                "link_puzzle" => config.link_puzzle = boolean(value, key)?,
+                "chart" => config.chart = boolean(value, key)?,
+                "heading_level" => {
+                    config.heading_level = value
+                        .get::<f64>()
+                        .filter(|l| (1.0..=6.0).contains(*l) && l.fract() == 0.0)
+                        .map(|l| *l as usize)
+                        .ok_or("expected `heading_level` to be a number from 1 to 6.")?;
+                }
+                _ => return Err(format!("unknown key `benchmark_table.{key}`.")),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+fn boolean(value: &JsonValue, key: &str) -> Result<bool, String> {
+    value
+        .get::<bool>()
+        .copied()
+        .ok_or_else(|| format!("expected `{key}` to be a boolean."))
+}
+
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
+}
+
+/// Returns the marker around the table of a year, e.g. `<!--- benchmarking table 2024 --->`.
+pub fn year_marker(name: &str, year: Year) -> String {
+    format!("<!--- {name} {year} --->")
+}
+
+/// Replaces the table of a year, which includes its markers, in a readme.
+/// A table between markers without a year, as written by older versions, is taken over by the year.
+/// If the year has no table yet, the table is added after the tables of other years, or at the end of the readme.
+pub fn replace_table(
+    readme: &mut String,
+    name: &str,
+    year: Year,
+    table: &str,
+) -> Result<(), Error> {
+    let marker = year_marker(name, year);
+    let legacy_marker = format!("<!--- {name} --->");
+
+    for marker in [marker, legacy_marker] {
+        if readme.contains(&marker) {
+            let positions = locate_table(readme, &marker)?;
+            readme.replace_range(positions.pos_start..positions.pos_end, table);
+            return Ok(());
+        }
+    }
+
+    match last_year_marker_end(readme, name) {
+        Some(end) => readme.insert_str(end, &format!("\n\n{table}")),
+        None => {
+            let trimmed = readme.trim_end().len();
+            readme.replace_range(trimmed.., &format!("\n\n{table}\n"));
+        }
+    }
+    Ok(())
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Returns the end of the last marker of a table with a year, e.g. `<!--- benchmarking table 2023 --->`.
+fn last_year_marker_end(readme: &str, name: &str) -> Option<usize> {
+    let prefix = format!("<!--- {name} ");
+
+    readme.rmatch_indices(&prefix).find_map(|(start, _)| {
+        let rest = &readme[start + prefix.len()..];
+        let (year, _) = rest.split_once(" --->")?;
+        (!year.is_empty() && year.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| start + prefix.len() + year.len() + " --->".len())
+    })
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates a table between two markers. A single marker is replaced by the table, so the table can be added to a readme.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
+            "too many occurences of marker in README.".into(),
         ));
     }
 
@@ -52,75 +339,128 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    config: &TableConfig,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    chart_path: Option<&str>,
+) -> String {
+    let header = format!("{} Benchmarks ({year})", "#".repeat(config.heading_level));
+
+    let marker = year_marker(MARKER_NAME, year);
 
+    let titles: Vec<&str> = config.columns.iter().map(|c| c.title()).collect();
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.clone(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", titles.join(" | ")),
+        format!("|{}", " :---: |".repeat(titles.len())),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let mut data = timings.data;
+    config.sort(&mut data);
+
+    for timing in &data {
+        let cells: Vec<String> = config
+            .columns
+            .iter()
+            .map(|c| c.cell(config, year, timing))
+            .collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+
+    if let Some(chart_path) = chart_path {
+        lines.push(String::new());
+        lines.push(format!(
+            "![Runtime of each part on a log scale](./{chart_path})"
+        ));
+    }
+
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+fn update_content(
+    s: &mut String,
+    config: &TableConfig,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    chart_path: Option<&str>,
+) -> Result<(), Error> {
+    let table = construct_table(config, year, timings, total_millis, chart_path);
+    replace_table(s, MARKER_NAME, year, &table)
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
     let path = "README.md";
+    let config = TableConfig::read_from_file()?;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+
+    // NOTE: the chart is only linked if it was written, there is no chart without benched parts.
+    let mut written_chart = None;
+    if config.chart {
+        if let Some(svg) = chart::runtime_svg(&format!("Runtime per part ({year})"), &timings) {
+            let chart_path = chart_path(year);
+            fs::create_dir_all(".assets")?;
+            fs::write(&chart_path, svg)?;
+            written_chart = Some(chart_path);
+        }
+    }
+
+    update_content(
+        &mut readme,
+        &config,
+        year,
+        timings,
+        total_millis,
+        written_chart.as_deref(),
+    )?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{update_content, TableConfig};
+    use crate::{
+        day,
+        template::timings::{PartTiming, Timing, Timings},
+        template::Year,
+    };
+
+    const MARKER: &str = "<!--- benchmarking table 2024 --->";
+
+    fn year() -> Year {
+        Year::new(2024).unwrap()
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    part_1: Some(PartTiming::new(10e6, None)),
+                    part_2: Some(PartTiming::new(20e6, None)),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    part_1: Some(PartTiming::new(30e6, None)),
+                    part_2: Some(PartTiming::new(40e6, None)),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    part_1: Some(PartTiming::new(40e6, None)),
+                    part_2: Some(PartTiming::new(50e6, None)),
                     total_nanos: 9e+10,
                 },
             ],
@@ -128,31 +468,107 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn errors_if_marker_not_present() {
-        let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+    fn appends_table_if_marker_not_present() {
+        let mut s = "# readme\n".to_string();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            year(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
+        assert!(s.starts_with(&format!("# readme\n\n{MARKER}\n## Benchmarks (2024)")));
+        assert!(s.ends_with(&format!("{MARKER}\n")));
+    }
+
+    #[test]
+    fn adds_table_after_other_years() {
+        let other = "<!--- benchmarking table 2023 --->";
+        let mut s = format!("foo\n{other}\n{other}\nbar");
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            year(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
+        assert!(s.starts_with(&format!("foo\n{other}\n{other}\n\n{MARKER}\n")));
+        assert!(s.ends_with(&format!("{MARKER}\nbar")));
+    }
+
+    #[test]
+    fn takes_over_table_without_year() {
+        let legacy = "<!--- benchmarking table --->";
+        let mut s = format!("foo\n{legacy}\n## Benchmarks\n{legacy}\nbar");
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            year(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
+        assert!(!s.contains(legacy));
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert!(s.ends_with(&format!("{MARKER}\nbar")));
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            year(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            year(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            year(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            year(),
+            get_mock_timings(),
+            190.0,
+            None,
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,24 +576,82 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &TableConfig::default(),
+            year(),
+            get_mock_timings(),
+            190.0,
+            Some(".assets/benchmarks-2024.svg"),
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2024 --->",
+            "## Benchmarks (2024)",
             "",
             "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2024/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2024/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2024/04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "",
+            "![Runtime of each part on a log scale](./.assets/benchmarks-2024.svg)",
+            "<!--- benchmarking table 2024 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn formats_configured_benchmarks() {
+        let config = TableConfig::try_from(
+            r#"{ "benchmark_table": { "columns": ["day", "total", "samples", "parse", "memory"], "sort": "total", "descending": true, "heading_level": 3, "link_source": false, "link_puzzle": true, "chart": false } }"#
+                .to_string(),
+        )
+        .unwrap();
+
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1 = Some(PartTiming {
+            samples: Some(100),
+            parse_nanos: Some(1.5e6),
+            memory_bytes: Some(1536),
+            ..PartTiming::new(10e6, None)
+        });
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, &config, year(), timings, 190.0, None).unwrap();
+        let expected = [
+            "<!--- benchmarking table 2024 --->",
+            "### Benchmarks (2024)",
+            "",
+            "| Day | Total | Samples | Parse | Memory |",
+            "| :---: | :---: | :---: | :---: | :---: |",
+            "| Day 4 ([puzzle](https://adventofcode.com/2024/day/4)) | `90.0s` | - | - | - |",
+            "| Day 2 ([puzzle](https://adventofcode.com/2024/day/2)) | `70.0s` | - | - | - |",
+            "| Day 1 ([puzzle](https://adventofcode.com/2024/day/1)) | `30.0s` | `100 / -` | `1.5ms` | `1.5 KiB` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table 2024 --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        assert!(TableConfig::try_from("{}".to_string()).is_ok());
+        assert!(TableConfig::try_from(
+            r#"{ "benchmark_table": { "columns": ["speed"] } }"#.to_string()
+        )
+        .is_err());
+        assert!(TableConfig::try_from(
+            r#"{ "benchmark_table": { "heading_level": 7 } }"#.to_string()
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,148 @@
+/// Module that updates the readme with a table of the solved parts of a year.
+/// Parts are solved if their answer is known or they have been benchmarked, so no request to the leaderboard is needed.
+use std::fs;
+
+use crate::template::answers::Answers;
+use crate::template::readme_benchmarks::{replace_table, year_marker, Error};
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, Year};
+
+/// The name of the markers around the table of a year, e.g. `<!--- advent_readme_stars table 2024 --->`.
+static MARKER_NAME: &str = "advent_readme_stars table";
+
+/// Returns whether a part has been solved.
+fn is_solved(answers: &Answers, timings: &Timings, day: Day, part: u8) -> bool {
+    answers.get(day, part).is_some() || timings.get(day).is_some_and(|t| t.part(part).is_some())
+}
+
+fn construct_table(year: Year, answers: &Answers, timings: &Timings) -> String {
+    let marker = year_marker(MARKER_NAME, year);
+
+    let mut lines: Vec<String> = vec![
+        marker.clone(),
+        format!("## {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    let mut stars = 0;
+    for day in all_days(year) {
+        let solved = [1, 2].map(|part| is_solved(answers, timings, day, part));
+        if !solved.contains(&true) {
+            continue;
+        }
+
+        stars += solved.iter().filter(|s| **s).count();
+        let [part_1, part_2] = solved.map(|s| if s { "⭐" } else { " " });
+        lines.push(format!(
+            "| [Day {}](https://adventofcode.com/{year}/day/{}) | {part_1} | {part_2} |",
+            day.into_inner(),
+            day.into_inner()
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!(
+        "**Stars: {stars} of {}**",
+        all_days(year).count() * 2
+    ));
+    lines.push(marker);
+
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    answers: &Answers,
+    timings: &Timings,
+) -> Result<(), Error> {
+    let table = construct_table(year, answers, timings);
+    replace_table(s, MARKER_NAME, year, &table)
+}
+
+/// Updates the stars table in the readme from the answers and timings stored for the year.
+pub fn update(year: Year) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(
+        &mut readme,
+        year,
+        &Answers::read_from_file(year),
+        &Timings::read_from_file(year).map_err(Error::Parser)?,
+    )?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::update_content;
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            timings::{PartTiming, Timing, Timings},
+            Year,
+        },
+    };
+
+    const MARKER: &str = "<!--- advent_readme_stars table 2024 --->";
+
+    #[test]
+    fn formats_stars() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "42".into());
+        answers.set(day!(1), 2, "7".into());
+        answers.set(day!(3), 1, "5".into());
+
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(3),
+                part_1: Some(PartTiming::new(1000.0, None)),
+                part_2: None,
+                total_nanos: 1000.0,
+            }],
+        };
+
+        // NOTE: a single marker, as left by the workflow, is replaced by the table.
+        let mut s = format!("foo\n{MARKER}\nbar");
+        update_content(&mut s, Year::new(2024).unwrap(), &answers, &timings).unwrap();
+        update_content(&mut s, Year::new(2024).unwrap(), &answers, &timings).unwrap();
+
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table 2024 --->",
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2024/day/3) | ⭐ |   |",
+            "",
+            "**Stars: 3 of 50**",
+            "<!--- advent_readme_stars table 2024 --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+    #[test]
+    fn keeps_tables_of_other_years() {
+        let other = "<!--- advent_readme_stars table 2023 --->";
+        let mut s = format!("foo\n{other}\n## 2023 Results\n{other}\nbar");
+        update_content(
+            &mut s,
+            Year::new(2024).unwrap(),
+            &Answers::default(),
+            &Timings::default(),
+        )
+        .unwrap();
+
+        assert!(s.starts_with(&format!(
+            "foo\n{other}\n## 2023 Results\n{other}\n\n{MARKER}\n## 2024 Results"
+        )));
+        assert!(s.ends_with(&format!("**Stars: 0 of 50**\n{MARKER}\nbar")));
+    }
+}
@@ -0,0 +1,27 @@
+/// Types that describe the solutions compiled into the main binary.
+/// The registry itself is generated by the build script from the files in `src/bin/<year>`.
+use std::io::Write;
+
+use crate::template::{runner::PartResult, PuzzleId};
+
+/// A single part of a solution that can be invoked in-process.
+#[derive(Clone, Copy)]
+pub struct Part {
+    pub part: u8,
+    /// Runs the part against the provided input and writes its result to the writer.
+    /// The flag controls whether the part is benched.
+    pub run: fn(&str, bool, &mut dyn Write) -> PartResult,
+}
+
+/// A registered solution, created by the [`solution!`](crate::solution) macro.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub parts: &'static [Part],
+}
+
+/// Looks up the solution for a given puzzle.
+#[must_use]
+pub fn find(solutions: &[Solution], puzzle: PuzzleId) -> Option<&Solution> {
+    solutions.iter().find(|s| s.puzzle == puzzle)
+}
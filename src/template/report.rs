@@ -0,0 +1,225 @@
+//! Module that writes benchmark reports as self-contained HTML files.
+//! Charts are inlined as SVG, so reports can be opened offline and shared as a single file.
+use std::{fmt::Write, fs, io, path::PathBuf};
+
+use crate::template::{
+    chart::{distribution_svg, escape, runtime_svg, sparkline_svg},
+    git,
+    timing_history::TimingHistory,
+    timings::{format_bytes, format_nanos, PartTiming, Timings},
+    Day, Year,
+};
+
+const STYLE: &str =
+    "body { background: #0f0f23; color: #cccccc; font-family: monospace; margin: 2em; }
+h1, h2 { color: #ffffff; font-weight: normal; }
+table { border-collapse: collapse; }
+th, td { padding: 0.25em 0.75em; text-align: right; border-bottom: 1px solid #333340; }
+td svg { vertical-align: middle; }";
+
+/// Writes a report of the stored timings, updated with the parts of a benchmark run,
+/// to `target/benchmark-report-<year>.html` and returns its path.
+pub fn write_html(
+    year: Year,
+    stored: &Timings,
+    benched: &Timings,
+    history: &TimingHistory,
+) -> io::Result<PathBuf> {
+    let path = PathBuf::from(format!("target/benchmark-report-{year}.html"));
+    fs::create_dir_all("target")?;
+    fs::write(
+        &path,
+        html(year, git::head().as_deref(), stored, benched, history),
+    )?;
+    Ok(path)
+}
+
+fn html(
+    year: Year,
+    revision: Option<&str>,
+    stored: &Timings,
+    benched: &Timings,
+    history: &TimingHistory,
+) -> String {
+    // NOTE: days that are not benched again, e.g. without `--all`, are reported with their stored timings.
+    let timings = &stored.merge(benched);
+    let title = format!("Benchmarks ({year})");
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+    );
+
+    let revision = revision.map_or_else(
+        || "an unknown revision".into(),
+        |r| format!("revision {}", escape(&r[..r.len().min(7)])),
+    );
+    let _ = writeln!(
+        out,
+        "<p>Benched at {revision}, total {:.2}ms, {} runs in the history.</p>",
+        timings.total_millis(),
+        history.runs.len()
+    );
+
+    let Some(runtime) = runtime_svg("Mean runtime per part", timings) else {
+        out.push_str("<p>No part has been benched.</p>\n</body>\n</html>\n");
+        return out;
+    };
+
+    let _ = writeln!(out, "<h2>Runtime</h2>\n{runtime}");
+    if let Some(distribution) = distribution_svg("Distribution of samples per part", timings) {
+        let _ = writeln!(out, "<h2>Distribution</h2>\n{distribution}");
+    }
+
+    let _ = writeln!(out, "<h2>Details</h2>\n{}", details_table(timings, history));
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Formats every benched part as a table row, with its trend over the timing history.
+fn details_table(timings: &Timings, history: &TimingHistory) -> String {
+    let parts: Vec<(Day, u8, &PartTiming)> = timings
+        .data
+        .iter()
+        .flat_map(|t| [1, 2].map(|part| (t, part)))
+        .filter_map(|(t, part)| Some((t.day, part, t.part(part)?)))
+        .collect();
+
+    let has_parse = parts.iter().any(|(_, _, p)| p.parse_nanos.is_some());
+    let has_memory = parts.iter().any(|(_, _, p)| p.memory_bytes.is_some());
+
+    let mut header = vec!["Day", "Part", "Mean", "Samples", "Min", "Median", "Max"];
+    if has_parse {
+        header.push("Parse");
+    }
+    if has_memory {
+        header.push("Memory");
+    }
+    header.push("Trend");
+
+    let mut out = String::from("<table>\n<tr>");
+    for title in header {
+        let _ = write!(out, "<th>{title}</th>");
+    }
+    out.push_str("</tr>\n");
+
+    for (day, part, timing) in parts {
+        let quantile = |index: usize| {
+            timing
+                .quantiles
+                .map_or_else(|| "-".into(), |q| format_nanos(q[index]))
+        };
+
+        let mut cells = vec![
+            day.to_string(),
+            part.to_string(),
+            timing.formatted(),
+            timing.samples.map_or_else(|| "-".into(), |s| s.to_string()),
+            quantile(0),
+            quantile(2),
+            quantile(4),
+        ];
+        if has_parse {
+            cells.push(timing.parse_nanos.map_or_else(|| "-".into(), format_nanos));
+        }
+        if has_memory {
+            cells.push(timing.memory_bytes.map_or_else(|| "-".into(), format_bytes));
+        }
+
+        let trend: Vec<f64> = history
+            .runs
+            .iter()
+            .filter_map(|run| run.timings.get(day)?.part_nanos(part))
+            .collect();
+        cells.push(sparkline_svg(&trend).unwrap_or_else(|| "-".into()));
+
+        out.push_str("<tr>");
+        for cell in cells {
+            let _ = write!(out, "<td>{cell}</td>");
+        }
+        out.push_str("</tr>\n");
+    }
+
+    out.push_str("</table>");
+    out
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::html;
+    use crate::{
+        day,
+        template::{
+            timing_history::{Run, TimingHistory},
+            timings::{PartTiming, Timing, Timings},
+            Year,
+        },
+    };
+
+    fn timings(part_1: f64) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some(PartTiming {
+                    quantiles: Some([900.0, 950.0, 1000.0, 1100.0, 2000.0]),
+                    ..PartTiming::new(part_1, Some(10))
+                }),
+                part_2: None,
+                total_nanos: part_1,
+            }],
+        }
+    }
+
+    #[test]
+    fn renders_report() {
+        let history = TimingHistory {
+            runs: [2000.0, 1000.0]
+                .map(|nanos| Run {
+                    timestamp: 1_700_000_000,
+                    revision: None,
+                    timings: timings(nanos),
+                })
+                .to_vec(),
+        };
+
+        let report = html(
+            Year::new(2024).unwrap(),
+            Some("abcdef123456"),
+            &Timings::default(),
+            &timings(1000.0),
+            &history,
+        );
+
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("Benched at revision abcdef1"));
+        assert!(report.contains("<h2>Distribution</h2>"));
+        assert!(report.contains("<td>01</td><td>1</td><td>1.0µs</td><td>10</td><td>900.0ns</td>"));
+        assert!(report.contains("<polyline"));
+        assert!(!report.contains("<th>Parse</th>"));
+        assert!(!report.contains("<th>Memory</th>"));
+        assert!(!report.contains("<script"));
+        assert!(!report.contains("src="));
+    }
+
+    #[test]
+    fn reports_stored_days_that_were_not_benched() {
+        let mut stored = timings(3000.0);
+        stored.data.push(Timing {
+            day: day!(2),
+            ..stored.data[0].clone()
+        });
+
+        let report = html(
+            Year::new(2024).unwrap(),
+            None,
+            &stored,
+            &timings(1000.0),
+            &TimingHistory::default(),
+        );
+
+        assert!(report.contains("Benched at an unknown revision, total 0.00ms"));
+        assert!(report.contains("<td>01</td><td>1</td><td>1.0µs</td>"));
+        assert!(report.contains("<td>02</td><td>1</td><td>3.0µs</td>"));
+    }
+}
@@ -1,40 +1,91 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, PuzzleId, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    registry::{self, Solution},
+    summary::{DayOutcome, PartOutcome, Status},
+    timings::{PartTiming, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// How [`run_multi`] invokes the solutions.
+#[derive(Clone, Copy)]
+pub enum Mode {
+    /// Call the solutions in-process through the registry compiled into the main binary.
+    Registry(&'static [Solution]),
+    /// Run every solution as a separate `cargo run --bin <year>-<day>` child process.
+    Binaries { is_release: bool },
+}
 
-    let mut need_space = false;
+/// The outcome of [`run_multi`]. Timings are only collected for timed runs.
+pub struct RunResult {
+    pub days: Vec<DayOutcome>,
+    pub timings: Option<Timings>,
+}
 
+/// Run the solutions for a set of days of a year, using up to `jobs` days in parallel.
+/// Output is printed per day and in day order. Timed runs are always executed serially.
+///
+/// Days that run longer than `timeout` are reported as timed out. Solution bins are killed, in-process
+/// solutions cannot be interrupted and keep running in the background until the command exits.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    mode: Mode,
+    is_timed: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> RunResult {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days: Vec<PuzzleId> = all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
+        .map(|day| PuzzleId::new(year, day))
+        .collect();
+
+    let mut outcomes: Vec<DayOutcome> = Vec::with_capacity(days.len());
+    let mut timings: Vec<Timing> = Vec::with_capacity(days.len());
+
+    if is_timed || jobs <= 1 {
+        for (index, day) in days.iter().enumerate() {
+            if index > 0 {
                 println!();
             }
-            need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
-
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let (outcome, timing) = run_day(*day, mode, is_timed, timeout, &mut io::stdout());
+            outcomes.push(outcome);
+            timings.extend(timing);
+        }
+    } else {
+        let mut need_space = false;
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+        run_parallel(&days, mode, jobs, timeout, |output, outcome| {
+            let mut stdout = io::stdout().lock();
+            if need_space {
+                let _ = writeln!(stdout);
             }
+            need_space = true;
+
+            let _ = stdout.write_all(&output);
+            let _ = stdout.flush();
+
+            outcomes.push(outcome);
         });
+    }
 
-    if is_timed {
+    let timings = if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -43,9 +94,163 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         Some(timings)
     } else {
         None
+    };
+
+    RunResult {
+        days: outcomes,
+        timings,
+    }
+}
+
+/// Run the solution of a single day and write its output block to `out`.
+fn run_day(
+    puzzle: PuzzleId,
+    mode: Mode,
+    is_timed: bool,
+    timeout: Option<Duration>,
+    out: &mut dyn Write,
+) -> (DayOutcome, Option<Timing>) {
+    let _ = writeln!(out, "{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+    let _ = writeln!(out, "------");
+
+    // NOTE: solution bins are killed by `run_solution` when they time out.
+    let Some(timeout) = timeout.filter(|_| matches!(mode, Mode::Registry(_))) else {
+        return run_solution(puzzle, mode, is_timed, timeout, out);
+    };
+
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut output = vec![];
+        let result = run_solution(puzzle, mode, is_timed, None, &mut output);
+        let _ = tx.send((output, result));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok((output, result)) => {
+            let _ = out.write_all(&output);
+            result
+        }
+        Err(_) => {
+            let _ = writeln!(out, "Timed out after {timeout:.1?}.");
+
+            let parts = match mode {
+                Mode::Registry(solutions) => registry::find(solutions, puzzle)
+                    .map(|s| s.parts.iter().map(|p| p.part).collect())
+                    .unwrap_or_default(),
+                Mode::Binaries { .. } => vec![1, 2],
+            };
+
+            let outcome = DayOutcome {
+                day: puzzle.day,
+                parts: parts
+                    .into_iter()
+                    .map(|part| PartOutcome::failed(part, Status::Timeout))
+                    .collect(),
+            };
+
+            (outcome, None)
+        }
+    }
+}
+
+fn run_solution(
+    puzzle: PuzzleId,
+    mode: Mode,
+    is_timed: bool,
+    timeout: Option<Duration>,
+    out: &mut dyn Write,
+) -> (DayOutcome, Option<Timing>) {
+    let day = puzzle.day;
+
+    match mode {
+        Mode::Registry(solutions) => {
+            let Some(solution) = registry::find(solutions, puzzle) else {
+                let _ = writeln!(out, "Not solved.");
+                return (DayOutcome { day, parts: vec![] }, None);
+            };
+
+            let parts = in_process::run_solution(solution, is_timed, out);
+            let timing = is_timed.then(|| in_process::to_timing(day, &parts));
+            (DayOutcome { day, parts }, timing)
+        }
+        Mode::Binaries { is_release } => {
+            // skip command invocation for days that have not been scaffolded yet.
+            if !Path::new(&puzzle.bin_path()).exists() {
+                let _ = writeln!(out, "Not solved.");
+                return (DayOutcome { day, parts: vec![] }, None);
+            }
+
+            let (output, exit) =
+                child_commands::run_solution(puzzle, is_timed, is_release, timeout, out).unwrap();
+
+            let mut parts =
+                child_commands::parse_outcomes(&output, exit != child_commands::Exit::Failure);
+
+            if exit == child_commands::Exit::TimedOut {
+                let _ = writeln!(out, "Timed out after {:.1?}.", timeout.unwrap_or_default());
+                for part in [1, 2] {
+                    if !parts.iter().any(|p| p.part == part) {
+                        parts.push(PartOutcome::failed(part, Status::Timeout));
+                    }
+                }
+            } else if output.is_empty() {
+                let _ = writeln!(out, "Not solved.");
+            }
+
+            let timing = (is_timed && !output.is_empty())
+                .then(|| child_commands::parse_exec_time(&output, day));
+            (DayOutcome { day, parts }, timing)
+        }
     }
 }
 
+/// Run days on `jobs` worker threads, buffering each day's output.
+/// `on_done` receives the buffered blocks in day order as soon as they are available.
+fn run_parallel(
+    days: &[PuzzleId],
+    mode: Mode,
+    jobs: usize,
+    timeout: Option<Duration>,
+    mut on_done: impl FnMut(Vec<u8>, DayOutcome),
+) {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let tx = tx.clone();
+            let next = &next;
+
+            scope.spawn(move || {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let mut output = vec![];
+                    let (outcome, _) = run_day(*day, mode, false, timeout, &mut output);
+
+                    if tx.send((*day, output, outcome)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut remaining = days.iter();
+        let mut next_day = remaining.next();
+
+        for (day, output, outcome) in rx {
+            pending.insert(day, (output, outcome));
+
+            while let Some((output, outcome)) = next_day.and_then(|day| pending.remove(day)) {
+                on_done(output, outcome);
+                next_day = remaining.next();
+            }
+        }
+    });
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -59,32 +264,126 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Invokes solutions through the registry compiled into the main binary.
+mod in_process {
+    use super::{
+        fs, panic, AssertUnwindSafe, PartOutcome, PartTiming, Solution, Status, Timing, Write,
+    };
+    use crate::diagnostics::{self, ParseReport};
+    use crate::template::{get_file_path, Day};
+
+    /// Run all parts of a solution against its input.
+    pub fn run_solution(
+        solution: &Solution,
+        is_timed: bool,
+        out: &mut dyn Write,
+    ) -> Vec<PartOutcome> {
+        let input = match fs::read_to_string(get_file_path("inputs", solution.puzzle)) {
+            Ok(input) => input,
+            Err(e) => {
+                let _ = writeln!(out, "Could not open input file: {e}");
+                return solution
+                    .parts
+                    .iter()
+                    .map(|p| PartOutcome::failed(p.part, Status::Unsolved))
+                    .collect();
+            }
+        };
+
+        diagnostics::silence_reports();
+
+        solution
+            .parts
+            .iter()
+            .map(|part| {
+                match panic::catch_unwind(AssertUnwindSafe(|| {
+                    (part.run)(&input, is_timed, &mut *out)
+                })) {
+                    Ok(result) => PartOutcome {
+                        samples: u64::try_from(result.samples).ok(),
+                        quantiles: result.quantiles,
+                        parse_duration: result.parse_duration,
+                        memory_bytes: result.memory_bytes,
+                        ..PartOutcome::from_answer(result.part, result.answer, result.duration)
+                    },
+                    Err(payload) => {
+                        match payload.downcast_ref::<ParseReport>() {
+                            Some(report) => writeln!(
+                                out,
+                                "\rPart {}: failed to parse input\n{report}",
+                                part.part
+                            ),
+                            None => writeln!(out, "\rPart {}: panicked", part.part),
+                        }
+                        .ok();
+                        PartOutcome::failed(part.part, Status::Panicked)
+                    }
+                }
+            })
+            .collect()
+    }
+
+    pub fn to_timing(day: Day, parts: &[PartOutcome]) -> Timing {
+        let mut timing = Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            total_nanos: 0_f64,
+        };
+
+        for part in parts.iter().filter(|p| p.answer.is_some()) {
+            let Some(duration) = part.duration else {
+                continue;
+            };
+
+            timing.set_part(
+                part.part,
+                Some(PartTiming {
+                    quantiles: part.quantiles,
+                    parse_nanos: part.parse_duration.map(|d| d.as_nanos() as f64),
+                    memory_bytes: part.memory_bytes,
+                    ..PartTiming::new(duration.as_nanos() as f64, part.samples)
+                }),
+            );
+        }
+
+        timing
+    }
 }
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{Error, PartOutcome, PartTiming, Status};
+    use crate::template::{runner::split_heap, spans, Day, PuzzleId, ANSI_BOLD, ANSI_RESET};
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
+        io::{BufRead, BufReader, Write},
         process::{Command, Stdio},
+        sync::mpsc::{self, RecvTimeoutError},
         thread,
+        time::Duration,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    /// How a solution bin exited.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Exit {
+        Success,
+        Failure,
+        /// The bin ran longer than its timeout and was killed.
+        TimedOut,
+    }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    /// Run the solution bin for a given puzzle, killing it if it runs longer than `timeout`.
+    /// Returns the lines written to stdout and how the bin exited.
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+        timeout: Option<Duration>,
+        out: &mut dyn Write,
+    ) -> Result<(Vec<String>, Exit), Error> {
+        let bin_name = puzzle.to_string();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
@@ -97,7 +396,7 @@ pub mod child_commands {
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to `out`/stderr while grabbing stdout lines.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -116,16 +415,137 @@ pub mod child_commands {
             });
         });
 
+        // NOTE: killing the bin closes its stdout, which ends the loop below.
+        let (done, is_done) = mpsc::channel::<()>();
+        let pid = cmd.id();
+        let watchdog = timeout.map(|timeout| {
+            thread::spawn(move || match is_done.recv_timeout(timeout) {
+                Err(RecvTimeoutError::Timeout) => {
+                    kill_tree(pid);
+                    true
+                }
+                _ => false,
+            })
+        });
+
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
+            writeln!(out, "{line}")?;
             output.push(line);
         }
 
         thread.join().unwrap();
-        cmd.wait()?;
+        let status = cmd.wait()?;
+
+        let _ = done.send(());
+        let timed_out = watchdog.is_some_and(|watchdog| watchdog.join().unwrap_or(false));
+
+        let exit = if timed_out {
+            Exit::TimedOut
+        } else if status.success() {
+            Exit::Success
+        } else {
+            Exit::Failure
+        };
+
+        Ok((output, exit))
+    }
+
+    /// Kills a `cargo run` process together with the solution bin it started.
+    fn kill_tree(pid: u32) {
+        #[cfg(windows)]
+        let _ = Command::new("taskkill")
+            .args(["/T", "/F", "/PID", &pid.to_string()])
+            .status();
+
+        #[cfg(not(windows))]
+        {
+            let _ = Command::new("pkill")
+                .args(["-KILL", "-P", &pid.to_string()])
+                .status();
+            let _ = Command::new("kill")
+                .args(["-KILL", &pid.to_string()])
+                .status();
+        }
+    }
+
+    /// Parse the outcome of each part from the output of a solution bin.
+    /// Parts without a result are considered to have panicked if the bin failed.
+    pub fn parse_outcomes(output: &[String], success: bool) -> Vec<PartOutcome> {
+        let mut outcomes: Vec<PartOutcome> = vec![];
+
+        for line in output {
+            // NOTE: intermediate results are overwritten with a carriage return.
+            let line = line
+                .rsplit('\r')
+                .next()
+                .unwrap_or_default()
+                .replace(ANSI_BOLD, "")
+                .replace(ANSI_RESET, "");
+
+            if spans::is_tree_line(&line) {
+                if let Some(outcome) = outcomes.last_mut() {
+                    if let Some((spans::PARSE_PHASE, duration)) = spans::parse_top_level_line(&line)
+                    {
+                        outcome.parse_duration = Some(duration);
+                    }
+                }
+                continue;
+            }
+
+            let Some((part, result)) = line
+                .strip_prefix("Part ")
+                .and_then(|l| l.split_once(": "))
+                .and_then(|(part, result)| Some((part.parse::<u8>().ok()?, result)))
+            else {
+                // lines that do not start a part belong to the multi-line result of the previous part.
+                if let Some(answer) = outcomes.last_mut().and_then(|o| o.answer.as_mut()) {
+                    if answer.is_empty() {
+                        answer.push_str(&line);
+                    } else {
+                        answer.push('\n');
+                        answer.push_str(&line);
+                    }
+                }
+                continue;
+            };
+
+            let (timed_line, memory_bytes) = split_heap(&line);
+            let duration = parse_time(timed_line).map(|nanos| Duration::from_nanos(nanos as u64));
+            let samples = parse_samples(timed_line);
 
-        Ok(output)
+            let (status, answer) = if result.starts_with('✖') {
+                (Status::Unsolved, None)
+            } else if result.starts_with('▼') {
+                (Status::Solved, Some(String::new()))
+            } else {
+                let answer = result
+                    .rsplit_once(" (")
+                    .map_or(result, |(answer, _)| answer);
+                (Status::Solved, Some(answer.to_string()))
+            };
+
+            outcomes.push(PartOutcome {
+                part,
+                answer,
+                status,
+                duration,
+                samples,
+                quantiles: None,
+                parse_duration: None,
+                memory_bytes,
+            });
+        }
+
+        if !success {
+            for part in [1, 2] {
+                if !outcomes.iter().any(|o| o.part == part) {
+                    outcomes.push(PartOutcome::failed(part, Status::Panicked));
+                }
+            }
+        }
+
+        outcomes
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
@@ -136,57 +556,98 @@ pub mod child_commands {
             total_nanos: 0_f64,
         };
 
+        // NOTE: the parse time of a part is in the phase tree that follows its (multi-line) result.
+        let parse_nanos = |part: u8| {
+            let mut lines = output
+                .iter()
+                .skip_while(|l| !l.contains(&format!("Part {part}:")));
+            lines.next()?;
+            lines
+                .skip_while(|l| !spans::is_tree_line(l) && !l.starts_with("Part "))
+                .take_while(|l| spans::is_tree_line(l))
+                .filter_map(|l| spans::parse_top_level_line(l))
+                .find(|(label, _)| *label == spans::PARSE_PHASE)
+                .map(|(_, duration)| duration.as_nanos() as f64)
+        };
+
         output
             .iter()
             .filter_map(|l| {
+                let (l, memory_bytes) = split_heap(l);
                 if !l.contains(" samples)") {
                     return None;
                 }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
+                let Some(nanos) = parse_time(l) else {
                     eprintln!("Could not parse timings from line: {l}");
                     return None;
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                Some((
+                    part,
+                    PartTiming {
+                        memory_bytes,
+                        ..PartTiming::new(nanos, parse_samples(l))
+                    },
+                ))
             })
-            .for_each(|(part, timing_str, nanos)| {
+            .for_each(|(part, timing)| {
                 if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
+                    timings.set_part(1, Some(timing));
                 } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                    timings.set_part(2, Some(timing));
                 }
-
-                timings.total_nanos += nanos;
             });
 
+        for part in [1, 2] {
+            if let Some(timing) = timings.part(part).copied() {
+                timings.set_part(
+                    part,
+                    Some(PartTiming {
+                        parse_nanos: parse_nanos(part),
+                        ..timing
+                    }),
+                );
+            }
+        }
+
         timings
     }
 
+    /// Parses the sample count of a timed part, e.g. `(74.13ns @ 100000 samples)`.
+    fn parse_samples(line: &str) -> Option<u64> {
+        line.rsplit_once(" samples)")?
+            .0
+            .rsplit_once('@')?
+            .1
+            .trim()
+            .parse()
+            .ok()
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
+    fn parse_time(line: &str) -> Option<f64> {
         // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
         let str_timing = line
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
-            .trim();
+            .trim()
+            .trim_end_matches(')');
 
-        let parsed_timing = match str_timing {
+        match str_timing {
             s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
             s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
             s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
             s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        }
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,9 +666,9 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_exec_time, parse_outcomes};
 
-        use crate::day;
+        use crate::{day, template::summary::Status};
 
         #[test]
         fn parses_execution_times() {
@@ -220,8 +681,49 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.part_1.unwrap().nanos, 74.13_f64);
+            assert_eq!(res.part_1.unwrap().samples, Some(100000));
+            assert_approx_eq!(res.part_2.unwrap().nanos, 74130000_f64);
+            assert_eq!(res.part_2.unwrap().samples, Some(99999));
+        }
+
+        #[test]
+        fn parses_parse_times() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
+                    "Part 2: ▼ (2.0ms @ 10 samples)".into(),
+                    "#.#".into(),
+                    "  ├─ parse (1.5ms)".into(),
+                    "  │  └─ parse (0.5ms)".into(),
+                    "  └─ solve (0.5ms)".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.unwrap().parse_nanos, None);
+            assert_approx_eq!(res.part_2.unwrap().parse_nanos.unwrap(), 1500000_f64);
+        }
+
+        #[test]
+        fn parses_heap_memory() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 42 (74.13ns @ 100000 samples) [heap 1.5 KiB]".into(),
+                    "Part 2: ▼ (2.0ms @ 10 samples)".into(),
+                    "#.#".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.unwrap().memory_bytes, Some(1536));
+            assert_eq!(res.part_1.unwrap().samples, Some(100000));
+            assert_eq!(res.part_2.unwrap().memory_bytes, None);
+
+            let res = parse_outcomes(
+                &["Part 1: 42 (2.0ms @ 10 samples) [heap 12 B]".into()],
+                true,
+            );
+            assert_eq!(res[0].answer.as_deref(), Some("42"));
+            assert_eq!(res[0].memory_bytes, Some(12));
         }
 
         #[test]
@@ -235,8 +737,10 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.part_1.unwrap().nanos, 2e9_f64);
+            assert_eq!(res.part_1.unwrap().samples, Some(5));
+            assert_approx_eq!(res.part_2.unwrap().nanos, 1e8_f64);
+            assert_eq!(res.part_2.unwrap().samples, Some(1));
         }
 
         #[test]
@@ -253,5 +757,57 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_outcomes() {
+            let res = parse_outcomes(
+                &[
+                    "Part 1: \x1b[1m42\x1b[0m\rPart 1: \x1b[1m42\x1b[0m (1.5ms)".into(),
+                    "Part 2: ▼ (2.0ms)".into(),
+                    "#.#".into(),
+                    ".#.".into(),
+                    "  └─ parse (1.0ms)".into(),
+                ],
+                true,
+            );
+            assert_eq!(res.len(), 2);
+            assert_eq!(res[0].answer.as_deref(), Some("42"));
+            assert_eq!(res[0].duration.unwrap().as_micros(), 1500);
+            assert_eq!(res[1].answer.as_deref(), Some("#.#\n.#."));
+            assert_eq!(res[1].status, Status::Solved);
+            assert_eq!(res[0].parse_duration, None);
+            assert_eq!(res[1].parse_duration.unwrap().as_micros(), 1000);
+        }
+
+        #[test]
+        fn parses_failed_outcomes() {
+            let res = parse_outcomes(&["Part 1: ✖             ".into()], false);
+            assert_eq!(res.len(), 2);
+            assert_eq!(res[0].status, Status::Unsolved);
+            assert_eq!(res[1].status, Status::Panicked);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{run_parallel, Mode};
+    use crate::template::{all_days, PuzzleId, Year};
+
+    #[test]
+    fn parallel_output_is_in_day_order() {
+        let year = Year::new(2024).unwrap();
+        let days: Vec<_> = all_days(year).map(|day| PuzzleId::new(year, day)).collect();
+        let mut outputs = vec![];
+
+        run_parallel(&days, Mode::Registry(&[]), 4, None, |output, _| {
+            outputs.push(String::from_utf8(output).unwrap());
+        });
+
+        assert_eq!(outputs.len(), 25);
+        for (output, puzzle) in outputs.iter().zip(days) {
+            assert!(output.contains(&format!("Day {}", puzzle.day)));
+            assert!(output.ends_with("Not solved.\n"));
+        }
     }
 }
@@ -2,59 +2,159 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::diagnostics::{self, ParseReport};
+use crate::template::alloc;
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{AocClient, Verdict};
+use crate::template::spans::{self, Phase};
+use crate::template::submissions::Submissions;
+use crate::template::timings::{format_bytes, parse_bytes};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{PuzzleId, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// The outcome of running a single solution part.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    /// The minimum, lower quartile, median, upper quartile and maximum of the samples in nanoseconds, if benched.
+    pub quantiles: Option<[f64; 5]>,
+    /// The duration of the top-level `parse` phase of the first run, if the part has one.
+    pub parse_duration: Option<Duration>,
+    /// The peak heap memory of the first run in bytes, if the part was benched and the memory measured.
+    pub memory_bytes: Option<u64>,
+}
+
+/// The time a part took, averaged over its samples when benched.
+struct Measurement {
+    duration: Duration,
+    samples: u128,
+    quantiles: Option<[f64; 5]>,
+    memory_bytes: Option<u64>,
+}
+
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) {
+    let is_timed = env::args().any(|x| x == "--time");
+
+    // NOTE: inputs that fail to parse are reported instead of panicking with a backtrace.
+    diagnostics::silence_reports();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        execute_part(func, input, part, is_timed, &mut stdout())
+    }));
+
+    match result {
+        Ok(result) => {
+            if let Some(answer) = result.answer {
+                submit_result(answer, puzzle, part);
+            }
+        }
+        Err(payload) => match payload.downcast::<ParseReport>() {
+            Ok(report) => {
+                eprintln!("Part {part}: failed to parse input\n{report}");
+                process::exit(1);
+            }
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    }
+}
+
+/// Run a solution part, write its result to `out` and return it without submitting.
+pub fn execute_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    part: u8,
+    is_timed: bool,
+    out: &mut dyn Write,
+) -> PartResult {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, measurement, phases) = run_timed(
+        func,
+        input,
+        |result, out| print_result(out, result, &part_str, ""),
+        is_timed,
+        out,
+    );
+
+    print_result(out, &result, &part_str, &format_measurement(&measurement));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    for line in spans::format_tree(&phases) {
+        let _ = writeln!(out, "{line}");
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    PartResult {
+        part,
+        answer: result.map(|r| r.to_string()),
+        duration: measurement.duration,
+        samples: measurement.samples,
+        quantiles: measurement.quantiles,
+        parse_duration: phases
+            .iter()
+            .find(|phase| phase.label == spans::PARSE_PHASE)
+            .map(|phase| phase.duration),
+        memory_bytes: measurement.memory_bytes,
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. by default, the function is executed once.
+///  2. when timed, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// Phases and, when timed, the peak heap memory are only recorded during the first run, so they do not add to
+/// the benched samples.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    hook: impl Fn(&T, &mut dyn Write),
+    is_timed: bool,
+    out: &mut dyn Write,
+) -> (T, Measurement, Vec<Phase>) {
     let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
-
-        func(input)
+    let ((result, phases), memory_bytes) = if is_timed {
+        alloc::measure(|| spans::record(|| func(input)))
+    } else {
+        (spans::record(|| func(input)), None)
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
+    hook(&result, out);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let measurement = if is_timed {
+        Measurement {
+            memory_bytes,
+            ..bench(func, input, &base_time, out)
+        }
     } else {
-        (base_time, 1)
+        Measurement {
+            duration: base_time,
+            samples: 1,
+            quantiles: None,
+            memory_bytes: None,
+        }
     };
 
-    (result, run.0, run.1)
+    (result, measurement, phases)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    out: &mut dyn Write,
+) -> Measurement {
+    let _ = write!(out, " > {ANSI_ITALIC}benching{ANSI_RESET}");
+    let _ = out.flush();
 
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
@@ -67,11 +167,21 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
+    Measurement {
         #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+        duration: Duration::from_nanos(average_duration(&timers) as u64),
+        samples: bench_iterations,
+        quantiles: Some(quantiles(&mut timers)),
+        memory_bytes: None,
+    }
+}
+
+/// Returns the minimum, lower quartile, median, upper quartile and maximum of the samples in nanoseconds.
+#[allow(clippy::cast_precision_loss)]
+fn quantiles(timers: &mut [Duration]) -> [f64; 5] {
+    timers.sort_unstable();
+    let last = timers.len().saturating_sub(1);
+    [0, 1, 2, 3, 4].map(|quarter| timers[last * quarter / 4].as_nanos() as f64)
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -82,61 +192,76 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
+/// The start of the peak heap memory after the duration of a part, e.g. `[heap 1.5 KiB]`.
+const HEAP_PREFIX: &str = "[heap ";
+
+/// Formats the duration and the peak heap memory of a part, e.g. ` (74.1ms @ 14 samples) [heap 1.5 KiB]`.
+fn format_measurement(measurement: &Measurement) -> String {
+    let duration = measurement.duration;
+    let mut formatted = if measurement.samples == 1 {
         format!(" ({duration:.1?})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({duration:.1?} @ {} samples)", measurement.samples)
+    };
+    if let Some(bytes) = measurement.memory_bytes {
+        formatted.push_str(&format!(" {HEAP_PREFIX}{}]", format_bytes(bytes)));
     }
+    formatted
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// Splits the peak heap memory off a line printed by [`format_measurement`], e.g. `Part 1: 42 (1.2ms) [heap 1.5 KiB]`.
+pub fn split_heap(line: &str) -> (&str, Option<u64>) {
+    line.strip_suffix(']')
+        .and_then(|l| l.rsplit_once(HEAP_PREFIX))
+        .and_then(|(line, bytes)| Some((line.trim_end(), Some(parse_bytes(bytes)?))))
+        .unwrap_or((line, None))
+}
+
+fn print_result<T: Display>(
+    out: &mut dyn Write,
+    result: &Option<T>,
+    part: &str,
+    duration_str: &str,
+) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
+    let _ = match result {
         Some(result) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    write!(out, "{str}")
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    write!(out, "\r{str}\n{result}\n")
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    write!(out, "{str}")
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    write!(out, "\r{str}\n")
                 }
             }
         }
         None => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                write!(out, "{part}: ✖")
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                write!(out, "\r{part}: ✖             \n")
             }
         }
-    }
+    };
+    let _ = out.flush();
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. a session cookie is configured.
+fn submit_result<T: Display>(result: T, puzzle: PuzzleId, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,14 +277,42 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to submit result: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("Submitting result...");
+    match client.submit(puzzle, part, &result.to_string()) {
+        Ok(submission) => {
+            println!("{}", submission.message);
+
+            let mut submissions = Submissions::read_from_file(puzzle.year);
+            submissions.record(puzzle.day, part, result.to_string(), submission.verdict);
+            if let Err(e) = submissions.store_file(puzzle.year) {
+                eprintln!("Failed to store submission: {e}");
+            }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+            if submission.verdict != Verdict::Correct {
+                process::exit(1);
+            }
+
+            // NOTE: correct answers are recorded, so `all` can detect regressions.
+            let mut answers = Answers::read_from_file(puzzle.year);
+            answers.set(puzzle.day, part, result.to_string());
+            if let Err(e) = answers.store_file(puzzle.year) {
+                eprintln!("Failed to store answer: {e}");
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to submit result: {e}");
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,285 @@
+//! Module that records the duration of nested phases of a solution part, see [`timed!`](crate::timed).
+//! Phases are only recorded during the first run of a part. While a part is benched, entering a span
+//! only loads a global counter of active recordings, which measured about 2ns per span.
+use std::{
+    cell::RefCell,
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+/// Tree lines are indented, so they can be told apart from the lines of a multi-line answer.
+const INDENT: &str = "  ";
+
+/// The label of the top-level phase that is stored as the parse time of a part, e.g. `timed!("parse", parse(input))`.
+pub const PARSE_PHASE: &str = "parse";
+
+/// The number of threads that are recording, so spans outside of a recording skip the thread-local stack.
+static ACTIVE_RECORDINGS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The phases that are currently open, the outermost frame collects the finished top-level phases.
+    static RECORDING: RefCell<Option<Vec<Frame>>> = const { RefCell::new(None) };
+}
+
+/// A finished phase with its nested phases.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phase {
+    pub label: &'static str,
+    pub duration: Duration,
+    /// The number of times the phase was entered, e.g. in a loop. Durations are summed.
+    pub calls: u32,
+    pub children: Vec<Phase>,
+}
+
+struct Frame {
+    label: &'static str,
+    start: Instant,
+    children: Vec<Phase>,
+}
+
+/// A guard that records the time until it is dropped as a phase of the current part.
+/// Spans entered on other threads than the one running the part are not recorded.
+pub struct Span {
+    is_recording: bool,
+    // NOTE: spans must be dropped on the thread they were entered on.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Span {
+    #[inline]
+    pub fn enter(label: &'static str) -> Self {
+        let is_recording = ACTIVE_RECORDINGS.load(Ordering::Relaxed) > 0 && Span::push(label);
+
+        Span {
+            is_recording,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Opens a frame for the phase, returns whether the current thread is recording.
+    #[cold]
+    fn push(label: &'static str) -> bool {
+        RECORDING.with_borrow_mut(|recording| match recording {
+            Some(stack) => {
+                stack.push(Frame {
+                    label,
+                    start: Instant::now(),
+                    children: vec![],
+                });
+                true
+            }
+            None => false,
+        })
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if !self.is_recording {
+            return;
+        }
+
+        RECORDING.with_borrow_mut(|recording| {
+            let Some(stack) = recording else {
+                return;
+            };
+            // NOTE: the outermost frame is never closed by a span.
+            if stack.len() < 2 {
+                return;
+            }
+            let Some(frame) = stack.pop() else {
+                return;
+            };
+
+            let phase = Phase {
+                label: frame.label,
+                duration: frame.start.elapsed(),
+                calls: 1,
+                children: frame.children,
+            };
+            if let Some(parent) = stack.last_mut() {
+                merge(&mut parent.children, phase);
+            }
+        });
+    }
+}
+
+/// Adds a phase to its siblings, phases with the same label are combined.
+fn merge(siblings: &mut Vec<Phase>, phase: Phase) {
+    match siblings.iter_mut().find(|p| p.label == phase.label) {
+        Some(sibling) => {
+            sibling.duration += phase.duration;
+            sibling.calls += phase.calls;
+            for child in phase.children {
+                merge(&mut sibling.children, child);
+            }
+        }
+        None => siblings.push(phase),
+    }
+}
+
+/// Runs `f` and returns the phases that were entered while it ran.
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<Phase>) {
+    RECORDING.with_borrow_mut(|recording| {
+        *recording = Some(vec![Frame {
+            label: "",
+            start: Instant::now(),
+            children: vec![],
+        }]);
+    });
+
+    let active = ActiveRecording::start();
+    let result = f();
+    drop(active);
+
+    let phases = RECORDING
+        .with_borrow_mut(Option::take)
+        .and_then(|mut stack| stack.drain(..).next())
+        .map(|root| root.children)
+        .unwrap_or_default();
+
+    (result, phases)
+}
+
+/// Counts a recording as active until it is dropped, also when the part panics.
+struct ActiveRecording;
+
+impl ActiveRecording {
+    fn start() -> Self {
+        ACTIVE_RECORDINGS.fetch_add(1, Ordering::Relaxed);
+        ActiveRecording
+    }
+}
+
+impl Drop for ActiveRecording {
+    fn drop(&mut self) {
+        ACTIVE_RECORDINGS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Formats phases as a tree, e.g. `  ├─ parse (1.2ms)`.
+pub fn format_tree(phases: &[Phase]) -> Vec<String> {
+    let mut lines = vec![];
+    format_level(phases, INDENT, &mut lines);
+    lines
+}
+
+fn format_level(phases: &[Phase], prefix: &str, lines: &mut Vec<String>) {
+    for (index, phase) in phases.iter().enumerate() {
+        let is_last = index == phases.len() - 1;
+        let calls = if phase.calls > 1 {
+            format!(", {} calls", phase.calls)
+        } else {
+            String::new()
+        };
+
+        lines.push(format!(
+            "{prefix}{}{} ({:.1?}{calls})",
+            if is_last { "└─ " } else { "├─ " },
+            phase.label,
+            phase.duration
+        ));
+        format_level(
+            &phase.children,
+            &format!("{prefix}{}", if is_last { "   " } else { "│  " }),
+            lines,
+        );
+    }
+}
+
+/// Returns the duration of a top-level phase from its line of a phase tree, e.g. `  ├─ parse (1.2ms)`.
+pub fn parse_top_level_line(line: &str) -> Option<(&str, Duration)> {
+    let line = line.strip_prefix(INDENT)?;
+    let line = line
+        .strip_prefix("├─ ")
+        .or_else(|| line.strip_prefix("└─ "))?;
+    let (label, rest) = line.rsplit_once(" (")?;
+    let duration = rest.strip_suffix(')')?.split(", ").next()?;
+    let nanos = crate::template::timings::parse_duration(duration)?;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some((label, Duration::from_nanos(nanos.round() as u64)))
+}
+
+/// Returns whether a line of output is part of a phase tree.
+pub fn is_tree_line(line: &str) -> bool {
+    line.strip_prefix(INDENT)
+        .is_some_and(|l| l.trim_start_matches([' ', '│']).starts_with(['├', '└']))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_tree, is_tree_line, parse_top_level_line, record, Phase, Span};
+
+    fn phase(label: &'static str, millis: u64, children: Vec<Phase>) -> Phase {
+        Phase {
+            label,
+            duration: Duration::from_millis(millis),
+            calls: 1,
+            children,
+        }
+    }
+
+    #[test]
+    fn records_nested_spans() {
+        let (result, phases) = record(|| {
+            let _parse = Span::enter("parse");
+            for _ in 0..3 {
+                crate::timed!("line", ());
+            }
+            drop(_parse);
+            crate::timed!("solve", 42)
+        });
+
+        assert_eq!(result, 42);
+        let labels: Vec<&str> = phases.iter().map(|p| p.label).collect();
+        assert_eq!(labels, ["parse", "solve"]);
+        assert_eq!(phases[0].children.len(), 1);
+        assert_eq!(phases[0].children[0].calls, 3);
+    }
+
+    #[test]
+    fn ignores_spans_outside_of_recording() {
+        let _span = Span::enter("bench");
+        let (_, phases) = record(|| ());
+        drop(_span);
+        assert!(phases.is_empty());
+        assert_eq!(record(|| ()).1, vec![]);
+    }
+
+    #[test]
+    fn formats_tree() {
+        let phases = vec![
+            phase("parse", 2, vec![phase("grid", 1, vec![])]),
+            Phase {
+                calls: 12,
+                ..phase("search", 5, vec![])
+            },
+        ];
+
+        let lines = format_tree(&phases);
+        assert_eq!(
+            lines,
+            [
+                "  ├─ parse (2.0ms)",
+                "  │  └─ grid (1.0ms)",
+                "  └─ search (5.0ms, 12 calls)",
+            ]
+        );
+        assert!(lines.iter().all(|l| is_tree_line(l)));
+        assert_eq!(
+            parse_top_level_line(&lines[0]),
+            Some(("parse", Duration::from_millis(2)))
+        );
+        assert_eq!(parse_top_level_line(&lines[1]), None);
+        assert_eq!(
+            parse_top_level_line(&lines[2]),
+            Some(("search", Duration::from_millis(5)))
+        );
+        assert!(!is_tree_line("#.#"));
+        assert!(!is_tree_line("├─"));
+    }
+}
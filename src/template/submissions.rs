@@ -0,0 +1,208 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{aoc_client::Verdict, Day, Year};
+
+/// Returns the path of the submission log of a year, e.g. `./data/2024/submissions.json`.
+fn get_file_path(year: Year) -> String {
+    format!("./data/{year}/submissions.json")
+}
+
+/// Represents a single answer submitted to the puzzle server.
+#[derive(Clone, Debug)]
+pub struct SubmissionRecord {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// Represents all submissions of a year, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<SubmissionRecord>,
+}
+
+impl Submissions {
+    /// Rehydrate submissions from a JSON file. If not present, returns no submissions.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_file_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Submissions::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(format!("./data/{year}"))?;
+        let mut file = fs::File::create(get_file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Adds a submission at the current time.
+    pub fn record(&mut self, day: Day, part: u8, answer: String, verdict: Verdict) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        self.data.push(SubmissionRecord {
+            day,
+            part,
+            answer,
+            verdict,
+            timestamp,
+        });
+    }
+
+    /// Returns the submissions of a part, oldest first.
+    pub fn of_part(&self, day: Day, part: u8) -> impl Iterator<Item = &SubmissionRecord> {
+        self.data
+            .iter()
+            .filter(move |s| s.day == day && s.part == part)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(SubmissionRecord::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&SubmissionRecord> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &SubmissionRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.to_string()),
+        );
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for SubmissionRecord {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .and_then(|verdict| Verdict::from_str(verdict).ok())
+            .ok_or("Expected submission.verdict to be a verdict.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|timestamp| *timestamp as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        Ok(SubmissionRecord {
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+            timestamp,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::Submissions;
+    use crate::{day, template::aoc_client::Verdict};
+
+    #[test]
+    fn roundtrips_json() {
+        let mut submissions = Submissions::default();
+        submissions.record(day!(1), 1, "41".into(), Verdict::Incorrect);
+        submissions.record(day!(1), 1, "42".into(), Verdict::Correct);
+        submissions.record(day!(2), 1, "7".into(), Verdict::TooSoon);
+
+        let json = JsonValue::from(submissions).stringify().unwrap();
+        let submissions = Submissions::try_from(json).unwrap();
+
+        let verdicts: Vec<Verdict> = submissions.of_part(day!(1), 1).map(|s| s.verdict).collect();
+        assert_eq!(verdicts, [Verdict::Incorrect, Verdict::Correct]);
+        assert_eq!(submissions.data[2].answer, "7");
+    }
+}
@@ -0,0 +1,285 @@
+/// Module that summarizes the outcome of running multiple days.
+use std::{fmt::Display, time::Duration};
+
+use crate::template::answers::Answers;
+use crate::template::{Day, ANSI_BOLD, ANSI_RESET};
+
+const MAX_ANSWER_WIDTH: usize = 24;
+
+/// The status of a single solution part after a run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Panicked,
+    Timeout,
+    /// The part returned an answer that differs from the known answer.
+    Mismatch {
+        expected: String,
+    },
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        *self != Status::Solved
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => f.write_str("solved"),
+            Status::Unsolved => f.write_str("unsolved"),
+            Status::Panicked => f.write_str("panicked"),
+            Status::Timeout => f.write_str("timeout"),
+            Status::Mismatch { expected } => write!(f, "mismatch (expected {expected})"),
+        }
+    }
+}
+
+/// The outcome of a single solution part.
+#[derive(Clone, Debug)]
+pub struct PartOutcome {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    pub duration: Option<Duration>,
+    /// The number of samples `duration` is the mean of, if the part was benched.
+    pub samples: Option<u64>,
+    /// The quantiles of the samples in nanoseconds, if the part was benched in-process.
+    pub quantiles: Option<[f64; 5]>,
+    /// The duration of the part's top-level `parse` phase, if it has one.
+    pub parse_duration: Option<Duration>,
+    /// The peak heap memory of the part in bytes, if it was benched and the memory measured.
+    pub memory_bytes: Option<u64>,
+}
+
+impl PartOutcome {
+    /// Creates an outcome for a part that produced no answer.
+    pub fn failed(part: u8, status: Status) -> Self {
+        Self {
+            part,
+            answer: None,
+            status,
+            duration: None,
+            samples: None,
+            quantiles: None,
+            parse_duration: None,
+            memory_bytes: None,
+        }
+    }
+
+    /// Creates an outcome from a part's answer, which is solved if there is one.
+    pub fn from_answer(part: u8, answer: Option<String>, duration: Duration) -> Self {
+        Self {
+            part,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            answer,
+            duration: Some(duration),
+            samples: None,
+            quantiles: None,
+            parse_duration: None,
+            memory_bytes: None,
+        }
+    }
+}
+
+/// The outcome of a single day. Days that have not been scaffolded yet have no parts.
+#[derive(Clone, Debug)]
+pub struct DayOutcome {
+    pub day: Day,
+    pub parts: Vec<PartOutcome>,
+}
+
+/// Marks solved parts whose answer differs from the known answer as mismatched.
+pub fn check_answers(days: &mut [DayOutcome], answers: &Answers) {
+    for day in days {
+        for part in &mut day.parts {
+            let (Some(answer), Some(expected)) = (&part.answer, answers.get(day.day, part.part))
+            else {
+                continue;
+            };
+
+            if part.status == Status::Solved && answer != expected {
+                part.status = Status::Mismatch {
+                    expected: expected.to_string(),
+                };
+            }
+        }
+    }
+}
+
+/// Returns `true` if any part of any day failed.
+pub fn has_failures(days: &[DayOutcome]) -> bool {
+    days.iter()
+        .flat_map(|d| &d.parts)
+        .any(|p| p.status.is_failure())
+}
+
+/// Formats an answer for a table cell, truncating long and multi-line answers.
+pub fn format_answer(answer: Option<&str>) -> String {
+    let Some(answer) = answer else {
+        return "-".into();
+    };
+
+    let first_line = answer.lines().next().unwrap_or_default();
+
+    if first_line.chars().count() > MAX_ANSWER_WIDTH || first_line.len() < answer.len() {
+        let truncated: String = first_line.chars().take(MAX_ANSWER_WIDTH - 1).collect();
+        format!("{truncated}…")
+    } else {
+        first_line.to_string()
+    }
+}
+
+fn construct_table(days: &[DayOutcome]) -> Vec<String> {
+    let header = ["Day", "Part", "Status", "Answer", "Duration"].map(String::from);
+
+    let rows: Vec<[String; 5]> = days
+        .iter()
+        .flat_map(|d| d.parts.iter().map(move |p| (d.day, p)))
+        .map(|(day, part)| {
+            [
+                day.to_string(),
+                part.part.to_string(),
+                part.status.to_string(),
+                format_answer(part.answer.as_deref()),
+                part.duration
+                    .map_or_else(|| "-".into(), |d| format!("{d:.1?}")),
+            ]
+        })
+        .collect();
+
+    format_table(&header, &rows)
+}
+
+/// Formats a header and rows into lines with aligned columns.
+pub fn format_table<const N: usize>(header: &[String; N], rows: &[[String; N]]) -> Vec<String> {
+    let mut widths = header.clone().map(|h| h.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: &[String; N]| {
+        row.iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_row(header)];
+    lines.extend(rows.iter().map(format_row));
+    lines
+}
+
+/// Prints lines created by [`format_table`] with a bold header.
+pub fn print_table(lines: Vec<String>) {
+    let mut lines = lines.into_iter();
+    if let Some(header) = lines.next() {
+        println!("{ANSI_BOLD}{header}{ANSI_RESET}");
+    }
+    lines.for_each(|line| println!("{line}"));
+}
+
+/// Prints a table with the outcome of every part of every scaffolded day.
+pub fn print(days: &[DayOutcome]) {
+    let parts: Vec<&PartOutcome> = days.iter().flat_map(|d| &d.parts).collect();
+
+    if parts.is_empty() {
+        return;
+    }
+
+    let failed = parts.iter().filter(|p| p.status.is_failure()).count();
+
+    println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("-------");
+
+    print_table(construct_table(days));
+
+    println!(
+        "\n{} of {} parts solved, {failed} failed.",
+        parts.len() - failed,
+        parts.len()
+    );
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{check_answers, construct_table, has_failures, DayOutcome, PartOutcome, Status};
+    use crate::{
+        day,
+        template::answers::{Answer, Answers},
+    };
+
+    fn get_mock_outcomes() -> Vec<DayOutcome> {
+        vec![
+            DayOutcome {
+                day: day!(1),
+                parts: vec![
+                    PartOutcome::from_answer(1, Some("11".into()), Duration::from_micros(200)),
+                    PartOutcome::from_answer(2, Some("31".into()), Duration::from_micros(100)),
+                ],
+            },
+            DayOutcome {
+                day: day!(2),
+                parts: vec![],
+            },
+        ]
+    }
+
+    #[test]
+    fn detects_mismatched_answers() {
+        let mut days = get_mock_outcomes();
+        let answers = Answers {
+            data: vec![Answer {
+                day: day!(1),
+                part_1: Some("11".into()),
+                part_2: Some("32".into()),
+            }],
+        };
+
+        check_answers(&mut days, &answers);
+
+        assert_eq!(days[0].parts[0].status, Status::Solved);
+        assert_eq!(
+            days[0].parts[1].status,
+            Status::Mismatch {
+                expected: "32".into()
+            }
+        );
+        assert!(has_failures(&days));
+    }
+
+    #[test]
+    fn ignores_unscaffolded_days() {
+        let days = get_mock_outcomes();
+        assert!(!has_failures(&days));
+        assert_eq!(construct_table(&days).len(), 3);
+    }
+
+    #[test]
+    fn formats_table() {
+        let mut days = get_mock_outcomes();
+        days[0].parts[1] = PartOutcome::failed(2, Status::Panicked);
+
+        assert_eq!(
+            construct_table(&days),
+            [
+                "Day  Part  Status    Answer  Duration",
+                "01   1     solved    11      200.0µs",
+                "01   2     panicked  -       -",
+            ]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Module that provides the templates used to scaffold a solution.
+//! Templates are looked up in the user's `templates` directory first and fall back to the built-in ones.
+use std::{collections::BTreeSet, error::Error, fmt::Display, fs, path::Path};
+
+use crate::template::PuzzleId;
+
+static USER_TEMPLATES_DIR: &str = "templates";
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+const BUILTIN_TEMPLATES: [(&str, &str); 4] = [
+    (
+        DEFAULT_TEMPLATE,
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/default.txt"
+        )),
+    ),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "lines-of-ints",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/lines-of-ints.txt"
+        )),
+    ),
+    (
+        "sections",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/sections.txt"
+        )),
+    ),
+];
+
+/// The values that are substituted for the placeholders of a template.
+#[derive(Clone, Debug)]
+pub struct Placeholders {
+    pub puzzle: PuzzleId,
+    /// The title of the puzzle, e.g. `Day 1: Historian Hysteria`.
+    pub title: Option<String>,
+    /// The answers of the example for each part.
+    pub example_answers: [Option<String>; 2],
+}
+
+impl Placeholders {
+    pub fn new(puzzle: PuzzleId) -> Self {
+        Self {
+            puzzle,
+            title: None,
+            example_answers: [None, None],
+        }
+    }
+}
+
+/// Returns the source of a template by name.
+pub fn find(name: &str) -> Result<String, TemplateError> {
+    let path = Path::new(USER_TEMPLATES_DIR).join(format!("{name}.txt"));
+
+    if let Ok(source) = fs::read_to_string(path) {
+        return Ok(source);
+    }
+
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, source)| source.to_string())
+        .ok_or_else(|| TemplateError {
+            name: name.to_string(),
+            available: names(),
+        })
+}
+
+/// Returns the names of all built-in and user templates.
+pub fn names() -> Vec<String> {
+    let mut names: BTreeSet<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+    for entry in fs::read_dir(USER_TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .flatten()
+    {
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            if let Some(stem) = path.file_stem() {
+                names.insert(stem.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    names.into_iter().collect()
+}
+
+/// Substitutes the placeholders of a template:
+///
+///  - `%YEAR%`: the year, e.g. `2024`.
+///  - `%DAY%`: the padded day, e.g. `08`.
+///  - `%DAY_NUMBER%`: the day, e.g. `8`.
+///  - `%TITLE%`: the title of the puzzle, or `Day 8` if the puzzle has not been downloaded.
+///  - `%EXAMPLE_ANSWER_1%` / `%EXAMPLE_ANSWER_2%`: the expected example answer, e.g. `Some(42)` or `None`.
+pub fn render(source: &str, placeholders: &Placeholders) -> String {
+    let day = placeholders.puzzle.day;
+
+    let title = placeholders
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+    let [part_one, part_two] = placeholders
+        .example_answers
+        .clone()
+        .map(|answer| format_expected(answer.as_deref()));
+
+    source
+        .replace("%YEAR%", &placeholders.puzzle.year.to_string())
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%DAY%", &day.to_string())
+        .replace("%TITLE%", &title)
+        .replace("%EXAMPLE_ANSWER_1%", &part_one)
+        .replace("%EXAMPLE_ANSWER_2%", &part_two)
+}
+
+/// Formats an answer as the expected value of an example test.
+pub fn format_expected(answer: Option<&str>) -> String {
+    match answer {
+        None => "None".into(),
+        Some(answer) if answer.parse::<u64>().is_ok() => format!("Some({answer})"),
+        Some(answer) => format!("Some({answer:?}.to_string())"),
+    }
+}
+
+/// Extracts the title from a puzzle description, e.g. `--- Day 1: Historian Hysteria ---`.
+pub fn parse_title(puzzle: &str) -> Option<String> {
+    puzzle
+        .lines()
+        .find(|line| line.contains("--- Day "))
+        .map(|line| {
+            line.trim_matches(|c: char| c == '#' || c == '\\' || c == '-' || c.is_whitespace())
+                .to_string()
+        })
+        .filter(|title| !title.is_empty())
+}
+
+/// An error which is returned when a template does not exist.
+#[derive(Debug)]
+pub struct TemplateError {
+    name: String,
+    available: Vec<String>,
+}
+
+impl Error for TemplateError {}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown template `{}`, expecting one of: {}",
+            self.name,
+            self.available.join(", ")
+        )
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find, parse_title, render, Placeholders};
+    use crate::{
+        day,
+        template::{PuzzleId, Year},
+    };
+
+    fn placeholders() -> Placeholders {
+        Placeholders::new(PuzzleId::new(Year::new(2024).unwrap(), day!(8)))
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let mut placeholders = placeholders();
+        placeholders.title = Some("Day 8: Resonant Collinearity".into());
+        placeholders.example_answers = [Some("14".into()), Some("a,b".into())];
+
+        assert_eq!(
+            render(
+                "%YEAR% %DAY% %DAY_NUMBER% %TITLE% %EXAMPLE_ANSWER_1% %EXAMPLE_ANSWER_2%",
+                &placeholders
+            ),
+            "2024 08 8 Day 8: Resonant Collinearity Some(14) Some(\"a,b\".to_string())"
+        );
+    }
+
+    #[test]
+    fn renders_missing_values() {
+        assert_eq!(
+            render("%TITLE% %EXAMPLE_ANSWER_1%", &placeholders()),
+            "Day 8 None"
+        );
+    }
+
+    #[test]
+    fn finds_builtin_templates() {
+        assert!(find("grid").unwrap().contains("Map"));
+        assert!(find("unknown").is_err());
+    }
+
+    #[test]
+    fn parses_title() {
+        assert_eq!(
+            parse_title("\\--- Day 1: Historian Hysteria ---\n----------\n"),
+            Some("Day 1: Historian Hysteria".into())
+        );
+        assert_eq!(
+            parse_title("## --- Day 2: Red-Nosed Reports ---"),
+            Some("Day 2: Red-Nosed Reports".into())
+        );
+        assert_eq!(parse_title("no title"), None);
+    }
+}
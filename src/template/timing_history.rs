@@ -0,0 +1,231 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Error},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{git, timings::Timings, Year};
+
+/// Returns the path of the timing history of a year, e.g. `./data/2024/timings-history.json`.
+fn get_file_path(year: Year) -> String {
+    format!("./data/{year}/timings-history.json")
+}
+
+/// Represents the timings of a single benchmark run.
+#[derive(Clone, Debug)]
+pub struct Run {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// The git commit the run was benched at, if the project is a git repository.
+    pub revision: Option<String>,
+    pub timings: Timings,
+}
+
+/// Represents all benchmark runs of a year, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct TimingHistory {
+    pub runs: Vec<Run>,
+}
+
+impl TimingHistory {
+    /// Dehydrate the history to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(format!("./data/{year}"))?;
+        let mut file = fs::File::create(get_file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the history from a JSON file. If not present, returns an empty history.
+    /// Files that cannot be read or parsed are an error, so they are not overwritten by the next run.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        let path = get_file_path(year);
+        match fs::read_to_string(&path) {
+            Ok(contents) => TimingHistory::try_from(contents).map_err(|e| format!("{path}: {e}")),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(TimingHistory::default()),
+            Err(e) => Err(format!("{path}: {e}")),
+        }
+    }
+
+    /// Adds a run at the current time and git revision.
+    pub fn record(&mut self, timings: Timings) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        self.runs.push(Run {
+            timestamp,
+            revision: git::head(),
+            timings,
+        });
+    }
+
+    /// Returns the latest timing of every day, only considering runs at a revision that starts with `revision`.
+    pub fn baseline(&self, revision: Option<&str>) -> Timings {
+        self.runs
+            .iter()
+            .filter(|run| match (revision, &run.revision) {
+                (None, _) => true,
+                (Some(revision), Some(run_revision)) => run_revision.starts_with(revision),
+                (Some(_), None) => false,
+            })
+            .fold(Timings::default(), |baseline, run| {
+                baseline.merge(&run.timings)
+            })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<TimingHistory> for JsonValue {
+    fn from(value: TimingHistory) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "runs".into(),
+            JsonValue::Array(value.runs.into_iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for TimingHistory {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_runs = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("runs")
+            .ok_or("expected JSON document to have key `runs`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.runs` to be an array.")?;
+
+        Ok(TimingHistory {
+            runs: json_runs
+                .iter()
+                .map(Run::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Run> for JsonValue {
+    fn from(value: Run) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "revision".into(),
+            value.revision.map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("timings".into(), JsonValue::from(value.timings));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Run {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected run to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|timestamp| *timestamp as u64)
+            .ok_or("Expected run.timestamp to be a number.")?;
+
+        let revision = json
+            .get("revision")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected run.revision to be null or string.")?;
+
+        let timings = Timings::try_from(json.get("timings").ok_or("Expected run.timings.")?)?;
+
+        Ok(Run {
+            timestamp,
+            revision: revision.cloned(),
+            timings,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Run, TimingHistory};
+    use crate::{
+        day,
+        template::timings::{PartTiming, Timing, Timings},
+    };
+
+    fn run(revision: &str, day: u8, part_1: f64) -> Run {
+        Run {
+            timestamp: 1_700_000_000,
+            revision: Some(revision.into()),
+            timings: Timings {
+                data: vec![Timing {
+                    day: crate::template::Day::new(day).unwrap(),
+                    part_1: Some(PartTiming::new(part_1, None)),
+                    part_2: None,
+                    total_nanos: 0_f64,
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn builds_baseline_from_latest_runs() {
+        let history = TimingHistory {
+            runs: vec![
+                run("aaa1", 1, 10e6),
+                run("bbb2", 2, 20e6),
+                run("ccc3", 1, 30e6),
+            ],
+        };
+
+        let baseline = history.baseline(None);
+        assert_eq!(baseline.get(day!(1)).unwrap().part_nanos(1), Some(30e6));
+        assert_eq!(baseline.get(day!(2)).unwrap().part_nanos(1), Some(20e6));
+
+        let baseline = history.baseline(Some("aaa"));
+        assert_eq!(baseline.get(day!(1)).unwrap().part_nanos(1), Some(10e6));
+        assert!(baseline.get(day!(2)).is_none());
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let history = TimingHistory {
+            runs: vec![run("aaa1", 1, 10e6)],
+        };
+
+        let json = JsonValue::from(history).stringify().unwrap();
+        let history = TimingHistory::try_from(json).unwrap();
+
+        assert_eq!(history.runs[0].timestamp, 1_700_000_000);
+        assert_eq!(history.runs[0].revision, Some("aaa1".into()));
+        assert_eq!(history.runs[0].timings.data.len(), 1);
+    }
+}
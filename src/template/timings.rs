@@ -1,19 +1,142 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Error},
+    str::FromStr,
+    time::Duration,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// The version of the timings file format written by `store_file`.
+/// Version 1 stored parts as formatted strings, e.g. `"74.1ms"`, and is migrated when read.
+/// Version 2 stored parts without `quantiles`, which are read as unknown.
+const VERSION: f64 = 3.0;
+
+/// Returns the path of the timings file of a year, e.g. `./data/2024/timings.json`.
+fn get_file_path(year: Year) -> String {
+    format!("./data/{year}/timings.json")
+}
+
+/// Represents the benchmark of a single part.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PartTiming {
+    /// Mean time of a sample in nanoseconds.
+    pub nanos: f64,
+    /// Number of samples the mean was taken over, if known.
+    pub samples: Option<u64>,
+    /// Time spent in the top-level `parse` phase of the first run in nanoseconds, if the part has one.
+    /// See [`timed!`](crate::timed).
+    pub parse_nanos: Option<f64>,
+    /// The peak heap memory of the first run in bytes, if measured. See [`alloc::measure`](super::alloc::measure).
+    pub memory_bytes: Option<u64>,
+    /// The minimum, lower quartile, median, upper quartile and maximum of the samples in nanoseconds, if known.
+    pub quantiles: Option<[f64; 5]>,
+}
+
+impl PartTiming {
+    pub fn new(nanos: f64, samples: Option<u64>) -> Self {
+        Self {
+            nanos,
+            samples,
+            ..Self::default()
+        }
+    }
+
+    /// Formats the time like a `Duration` with one decimal, e.g. `74.1ms`.
+    pub fn formatted(&self) -> String {
+        format_nanos(self.nanos)
+    }
+}
+
+/// Formats nanoseconds like a `Duration` with one decimal, e.g. `74.1ms`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_nanos(nanos: f64) -> String {
+    format!(
+        "{:.1?}",
+        Duration::from_nanos(nanos.max(0.0).round() as u64)
+    )
+}
+
+/// Formats bytes with a binary unit, e.g. `1.5 MiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", units[unit])
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// Returns the benchmark of a part.
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the benchmarked time of a part in nanoseconds.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        self.part(part).map(|p| p.nanos)
+    }
+
+    /// Sets the benchmark of a part and updates the total.
+    pub fn set_part(&mut self, part: u8, timing: Option<PartTiming>) {
+        match part {
+            1 => self.part_1 = timing,
+            2 => self.part_2 = timing,
+            _ => return,
+        }
+        self.total_nanos = [&self.part_1, &self.part_2]
+            .iter()
+            .filter_map(|p| p.map(|p| p.nanos))
+            .sum();
+    }
+}
+
+/// Parses a duration formatted with `{:?}`, e.g. `74.1ms`, to nanoseconds.
+pub fn parse_duration(formatted: &str) -> Option<f64> {
+    let (number, factor) = [("ns", 1_f64), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)]
+        .iter()
+        .find_map(|(unit, factor)| Some((formatted.strip_suffix(unit)?, factor)))?;
+    number.trim().parse::<f64>().ok().map(|n| n * factor)
+}
+
+/// Parses bytes formatted with [`format_bytes`], e.g. `1.5 KiB`. Formatted values only keep one decimal of their unit.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn parse_bytes(formatted: &str) -> Option<u64> {
+    let (number, unit) = formatted.trim().split_once(' ')?;
+    let factor = match unit {
+        "B" => 1_f64,
+        "KiB" => 1024_f64,
+        "MiB" => 1024_f64.powi(2),
+        "GiB" => 1024_f64.powi(3),
+        _ => return None,
+    };
+    let number = number.parse::<f64>().ok().filter(|n| *n >= 0.0)?;
+    Some((number * factor).round() as u64)
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -23,35 +146,43 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        fs::create_dir_all(format!("./data/{year}"))?;
+        let mut file = fs::File::create(get_file_path(year))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    /// Files that cannot be read or parsed, e.g. because a newer version wrote them, are an error,
+    /// so they are not overwritten with fewer timings.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        let path = get_file_path(year);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Timings::try_from(contents).map_err(|e| format!("{path}: {e}")),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Timings::default()),
+            Err(e) => Err(format!("{path}: {e}")),
+        }
     }
 
-    /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// Merge two sets of timings, overwriting the parts of `self` with the parts of `other` if present.
     pub fn merge(&self, new: &Self) -> Self {
-        let mut data: Vec<Timing> = vec![];
+        let mut data: Vec<Timing> = self.data.clone();
 
         for timing in &new.data {
-            data.push(timing.clone());
-        }
-
-        for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
-                data.push(timing.clone());
+            match data.iter_mut().find(|t| t.day == timing.day) {
+                Some(existing) => {
+                    for part in [1, 2] {
+                        if let Some(new_part) = timing.part(part) {
+                            existing.set_part(part, Some(*new_part));
+                        }
+                    }
+                }
+                None => data.push(timing.clone()),
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,6 +191,11 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Returns the timing of a day, if it has been benched.
+    pub fn get(&self, day: Day) -> Option<&Timing> {
+        self.data.iter().find(|t| t.day == day)
+    }
+
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
@@ -73,6 +209,7 @@ impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("version".into(), JsonValue::Number(VERSION));
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -87,10 +224,32 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
 
-        let json_data = json
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
+
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: files without a version were written before parts were stored as numbers.
+        let version = match json.get("version") {
+            Some(version) => *version
+                .get::<f64>()
+                .ok_or("expected `json.version` to be a number.")?,
+            None => 1.0,
+        };
+        if version > VERSION {
+            return Err(format!(
+                "timings file version {version} is newer than the supported version {VERSION}."
+            ));
+        }
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
@@ -113,24 +272,19 @@ impl From<&Timing> for JsonValue {
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
-
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
         map.insert(
             "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_1
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
-
         map.insert(
             "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_2
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
 
         JsonValue::Object(map)
@@ -153,13 +307,13 @@ impl TryFrom<&JsonValue> for Timing {
 
         let part_1 = json
             .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+            .ok_or("Expected timing.part_1 to be present.")
+            .and_then(part_from_json)?;
 
         let part_2 = json
             .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+            .ok_or("Expected timing.part_2 to be present.")
+            .and_then(part_from_json)?;
 
         let total_nanos = json
             .get("total_nanos")
@@ -168,39 +322,128 @@ impl TryFrom<&JsonValue> for Timing {
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            part_1,
+            part_2,
             total_nanos,
         })
     }
 }
 
+/// Reads a part that is either null, an object or a formatted duration of version 1.
+fn part_from_json(value: &JsonValue) -> Result<Option<PartTiming>, &'static str> {
+    match value {
+        JsonValue::Null => Ok(None),
+        JsonValue::String(formatted) => parse_duration(formatted)
+            .map(|nanos| Some(PartTiming::new(nanos, None)))
+            .ok_or("Expected timing part to be a formatted duration."),
+        JsonValue::Object(_) => PartTiming::try_from(value).map(Some),
+        _ => Err("Expected timing part to be null or an object."),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartTiming> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        map.insert(
+            "samples".into(),
+            value
+                .samples
+                .map_or(JsonValue::Null, |s| JsonValue::Number(s as f64)),
+        );
+        map.insert(
+            "parse_nanos".into(),
+            value.parse_nanos.map_or(JsonValue::Null, JsonValue::Number),
+        );
+        map.insert(
+            "memory_bytes".into(),
+            value
+                .memory_bytes
+                .map_or(JsonValue::Null, |b| JsonValue::Number(b as f64)),
+        );
+        map.insert(
+            "quantiles".into(),
+            value.quantiles.map_or(JsonValue::Null, |q| {
+                JsonValue::Array(q.iter().copied().map(JsonValue::Number).collect())
+            }),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = &'static str;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing part to be a JSON object.")?;
+
+        // optional numbers may be missing or null.
+        let number = |key: &str| json.get(key).and_then(|v| v.get::<f64>().copied());
+
+        let quantiles = match json.get("quantiles") {
+            None | Some(JsonValue::Null) => None,
+            Some(value) => {
+                let quantiles: Vec<f64> = value
+                    .get::<Vec<JsonValue>>()
+                    .ok_or("Expected timing part.quantiles to be an array.")?
+                    .iter()
+                    .filter_map(|q| q.get::<f64>().copied())
+                    .collect();
+                Some(
+                    <[f64; 5]>::try_from(quantiles)
+                        .or(Err("Expected timing part.quantiles to be five numbers."))?,
+                )
+            }
+        };
+
+        Ok(PartTiming {
+            nanos: number("nanos").ok_or("Expected timing part.nanos to be a number.")?,
+            samples: number("samples").map(|s| s as u64),
+            parse_nanos: number("parse_nanos"),
+            memory_bytes: number("memory_bytes").map(|b| b as u64),
+            quantiles,
+        })
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
+
+    pub fn part(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming::new(millis * 1_000_000_f64, Some(100)))
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    part_1: part(10.0),
+                    part_2: part(20.0),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    part_1: part(30.0),
+                    part_2: part(40.0),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    part_1: part(40.0),
                     part_2: None,
                     total_nanos: 4e+10,
                 },
@@ -208,21 +451,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_part_durations() {
+        let timings = get_mock_timings();
+        assert_eq!(timings.data[0].part_nanos(1), Some(10_000_000_f64));
+        assert_eq!(timings.data[2].part_nanos(2), None);
+        assert_eq!(super::parse_duration("1.5s"), Some(1.5e9));
+        assert_eq!(super::parse_duration("350.0ns"), Some(350_f64));
+        assert_eq!(super::parse_duration("12.5µs"), Some(12_500_f64));
+    }
+
+    #[test]
+    fn parses_formatted_bytes() {
+        for bytes in [0, 12, 1536, 3 * 1024 * 1024] {
+            assert_eq!(super::parse_bytes(&super::format_bytes(bytes)), Some(bytes));
+        }
+        assert_eq!(super::parse_bytes("1.5 KiB"), Some(1536));
+        assert_eq!(super::parse_bytes("1.5KiB"), None);
+        assert_eq!(super::parse_bytes("1.5 kB"), None);
+    }
+
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::timings::{PartTiming, Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
+            let json = r#"{ "version": 3, "data": [{ "day": "01", "part_1": { "nanos": 1500, "samples": 10, "parse_nanos": 500, "memory_bytes": 2048, "quantiles": [1000, 1200, 1400, 1800, 3000] }, "part_2": null, "total_nanos": 1500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming {
+                    nanos: 1500_f64,
+                    samples: Some(10),
+                    parse_nanos: Some(500_f64),
+                    memory_bytes: Some(2048),
+                    quantiles: Some([1000_f64, 1200_f64, 1400_f64, 1800_f64, 3000_f64]),
+                })
+            );
+            assert_eq!(timing.part_2, None);
+        }
+
+        #[test]
+        fn migrates_formatted_timings() {
             let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_1, Some(PartTiming::new(1_000_000_f64, None)));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn migrates_timings_without_quantiles() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1500, "samples": 10 }, "part_2": null, "total_nanos": 1500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1, Some(PartTiming::new(1500_f64, Some(10))));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -237,6 +529,13 @@ mod tests {
             Timings::try_from(json).unwrap();
         }
 
+        #[test]
+        #[should_panic]
+        fn panics_for_newer_versions() {
+            let json = r#"{ "version": 4, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
@@ -250,6 +549,15 @@ mod tests {
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
+        #[test]
+        fn roundtrips_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = crate::template::timings::Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[2].part_2, None);
+        }
+
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
@@ -274,18 +582,20 @@ mod tests {
             template::timings::{Timing, Timings},
         };
 
+        use super::part;
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    part_1: part(1.0),
+                    part_2: part(2.0),
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +603,13 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    part_1: part(1.0),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -313,7 +623,7 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -323,7 +633,7 @@ mod tests {
             template::timings::{Timing, Timings},
         };
 
-        use super::get_mock_timings;
+        use super::{get_mock_timings, part};
 
         #[test]
         fn handles_disjunct_timings() {
@@ -352,8 +662,8 @@ mod tests {
                 data: vec![Timing {
                     day: day!(2),
                     part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
+                    part_2: part(5.0),
+                    total_nanos: 5_000_000_f64,
                 }],
             };
             let merged = timings.merge(&other);
@@ -361,7 +671,9 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].part_1, part(30.0));
+            assert_eq!(merged.data[1].part_2, part(5.0));
+            assert_eq!(merged.data[1].total_nanos, 35_000_000_f64);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
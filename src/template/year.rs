@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::{fs, io};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, CONFIG_PATH, MAX_DAY};
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+const FIRST_YEAR: u16 = 2015;
+
+/// The number of days of every event, starting from the year of each entry.
+/// Used for the years that are not configured in the `calendar` object of `aoc.json`.
+const CALENDAR: &[(u16, u8)] = &[(FIRST_YEAR, MAX_DAY), (2025, 12)];
+
+/// The number of days per year configured in `aoc.json`, read once.
+static CALENDAR_OVERRIDES: OnceLock<HashMap<u16, u8>> = OnceLock::new();
+
+/// A year of advent (i.e. an integer of 2015 or later).
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(FIRST_YEAR..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Returns the year configured with the `AOC_YEAR` environment variable, which defaults
+    /// to the value in `.cargo/config.toml`.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Returns the number of days of the event, e.g. 25 for 2024 and 12 from 2025 on.
+    /// The number can be overridden per year in the `calendar` object of `aoc.json`.
+    pub fn day_count(self) -> u8 {
+        CALENDAR_OVERRIDES
+            .get_or_init(read_calendar_overrides)
+            .get(&self.0)
+            .copied()
+            .unwrap_or_else(|| self.default_day_count())
+    }
+
+    /// Returns the number of days of the event according to the built-in calendar.
+    const fn default_day_count(self) -> u8 {
+        let mut count = MAX_DAY;
+        let mut i = 0;
+
+        while i < CALENDAR.len() {
+            if CALENDAR[i].0 <= self.0 {
+                count = CALENDAR[i].1;
+            }
+            i += 1;
+        }
+
+        count
+    }
+
+    /// Returns the number of days of the event in a const context, from the `calendar` of `aoc.json` that the
+    /// build script passes as `<year>:<days>` pairs, e.g. `2026:12,2027:25`.
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __day_count(self, calendar: Option<&str>) -> u8 {
+        let Some(calendar) = calendar else {
+            return self.default_day_count();
+        };
+        let bytes = calendar.as_bytes();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let mut year = 0;
+            while i < bytes.len() && bytes[i] != b':' {
+                year = year * 10 + (bytes[i] - b'0') as u16;
+                i += 1;
+            }
+            i += 1;
+
+            let mut days = 0;
+            while i < bytes.len() && bytes[i] != b',' {
+                days = days * 10 + (bytes[i] - b'0');
+                i += 1;
+            }
+            i += 1;
+
+            if year == self.0 {
+                return days;
+            }
+        }
+
+        self.default_day_count()
+    }
+
+    /// Returns `true` if the day is part of the event.
+    pub fn contains(self, day: Day) -> bool {
+        day.into_inner() <= self.day_count()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __from_source_path(path: &str, fallback: Option<&str>) -> Self {
+        // NOTE: solutions live in `src/bin/<year>/<day>.rs`, so the year is the name of the parent directory.
+        let bytes = path.as_bytes();
+        let mut end = bytes.len();
+
+        while end > 0 && !is_separator(bytes[end - 1]) {
+            end -= 1;
+        }
+
+        if end > 0 {
+            let mut start = end - 1;
+            while start > 0 && !is_separator(bytes[start - 1]) {
+                start -= 1;
+            }
+
+            if let Some(year) = parse_year(bytes, start, end - 1) {
+                return Self(year);
+            }
+        }
+
+        // solutions that have not been migrated yet fall back to `AOC_YEAR`.
+        match fallback {
+            Some(fallback) => match parse_year(fallback.as_bytes(), 0, fallback.len()) {
+                Some(year) => Self(year),
+                None => panic!("`AOC_YEAR` is not a valid year"),
+            },
+            None => panic!(
+                "could not determine the year of the solution, expecting `src/bin/<year>/<day>.rs`"
+            ),
+        }
+    }
+}
+
+/// Reads the calendar overrides of the project. Invalid overrides are reported and ignored, so commands
+/// that do not depend on the calendar keep working.
+fn read_calendar_overrides() -> HashMap<u16, u8> {
+    let contents = match fs::read_to_string(CONFIG_PATH) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return HashMap::new(),
+        Err(e) => {
+            eprintln!("Failed to read calendar from {CONFIG_PATH}: {e}");
+            return HashMap::new();
+        }
+    };
+
+    parse_calendar(&contents).unwrap_or_else(|e| {
+        eprintln!("Ignoring calendar in {CONFIG_PATH}: {e}");
+        HashMap::new()
+    })
+}
+
+/// Parses the `calendar` object of the project configuration, e.g. `{ "calendar": { "2026": 12 } }`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn parse_calendar(contents: &str) -> Result<HashMap<u16, u8>, String> {
+    let json = JsonValue::from_str(contents).or(Err("not valid JSON file."))?;
+
+    let Some(calendar) = json
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected JSON document to be an object.")?
+        .get("calendar")
+    else {
+        return Ok(HashMap::new());
+    };
+
+    calendar
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected `calendar` to be an object.")?
+        .iter()
+        .map(|(year, days)| {
+            let year = year
+                .parse::<Year>()
+                .map_err(|e| format!("invalid year `{year}` in `calendar`, {e}."))?;
+            let days = days
+                .get::<f64>()
+                .filter(|d| (1.0..=f64::from(MAX_DAY)).contains(*d) && d.fract() == 0.0)
+                .ok_or_else(|| {
+                    format!("expected the days of {year} to be a number from 1 to {MAX_DAY}.")
+                })?;
+
+            Ok((year.0, *days as u8))
+        })
+        .collect()
+}
+
+const fn is_separator(byte: u8) -> bool {
+    byte == b'/' || byte == b'\\'
+}
+
+const fn parse_year(bytes: &[u8], start: usize, end: usize) -> Option<u16> {
+    if end < start || end - start != 4 {
+        return None;
+    }
+
+    let mut year = 0;
+    let mut i = start;
+
+    while i < end {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    if year < FIRST_YEAR {
+        return None;
+    }
+
+    Some(year)
+}
+
+#[cfg(feature = "today")]
+impl Year {
+    /// Returns the current year in the timezone of the puzzle server.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(super::day::SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        Self::new(u16::try_from(today.year()).ok()?)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_calendar, Year};
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().unwrap(), Year(2024));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn counts_days_of_event() {
+        assert_eq!(Year(2015).default_day_count(), 25);
+        assert_eq!(Year(2024).default_day_count(), 25);
+        assert_eq!(Year(2025).default_day_count(), 12);
+        assert_eq!(Year(2030).default_day_count(), 12);
+    }
+
+    #[test]
+    fn counts_days_of_event_in_const_context() {
+        assert_eq!(Year(2025).__day_count(None), 12);
+        assert_eq!(Year(2025).__day_count(Some("")), 12);
+        assert_eq!(Year(2025).__day_count(Some("2025:25")), 25);
+        assert_eq!(Year(2027).__day_count(Some("2026:12,2027:20")), 20);
+        assert_eq!(Year(2024).__day_count(Some("2026:12,2027:20")), 25);
+    }
+
+    #[test]
+    fn parses_calendar_overrides() {
+        let calendar =
+            parse_calendar(r#"{ "benchmark_table": {}, "calendar": { "2026": 12, "2027": 25 } }"#)
+                .unwrap();
+        assert_eq!(calendar.get(&2026), Some(&12));
+        assert_eq!(calendar.get(&2027), Some(&25));
+
+        assert!(parse_calendar("{}").unwrap().is_empty());
+        assert!(parse_calendar(r#"{ "calendar": { "2014": 12 } }"#).is_err());
+        assert!(parse_calendar(r#"{ "calendar": { "2026": 26 } }"#).is_err());
+        assert!(parse_calendar(r#"{ "calendar": { "2026": 1.5 } }"#).is_err());
+    }
+
+    #[test]
+    fn reads_year_from_source_path() {
+        assert_eq!(
+            Year::__from_source_path("src/bin/2023/01.rs", None),
+            Year(2023)
+        );
+        assert_eq!(
+            Year::__from_source_path("C:\\aoc\\src\\bin\\2022\\01.rs", None),
+            Year(2022)
+        );
+        assert_eq!(
+            Year::__from_source_path("src/bin/01.rs", Some("2024")),
+            Year(2024)
+        );
+    }
+}